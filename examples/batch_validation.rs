@@ -1,10 +1,10 @@
-//! Example of batch validation with rate limiting
+//! Example of concurrent batch validation with rate limiting
 
-use credify::{LinkedInUrlError, LinkedInValidator};
-use std::thread;
-use std::time::Duration;
+use credify::{AIDecision, AsyncLinkedInValidator, BatchOptions};
+use futures_util::StreamExt;
 
-fn main() {
+#[tokio::main]
+async fn main() {
     println!("LinkedIn Profile Validator - Batch Validation Example\n");
 
     let urls_to_check = [
@@ -13,46 +13,41 @@ fn main() {
         "https://www.linkedin.com/in/sundarpichai",
         "https://www.linkedin.com/in/tim-cook-1a4b3b5c",
         "https://www.linkedin.com/in/markzuckerberg",
+        // Same profile as the first one, validated only once
+        "https://uk.linkedin.com/in/BillGates/",
     ];
 
-    let validator = match LinkedInValidator::new() {
+    let validator = match AsyncLinkedInValidator::new() {
         Ok(v) => v,
         Err(e) => {
             eprintln!("[CLIENT_BUILD_ERROR] Failed to create validator: {e}");
             return;
         }
     };
-    let delay = Duration::from_secs(2); // 2 second delay between requests
+
+    // At most 4 validations in flight, started at 2 per second after a burst of 2
+    let options = BatchOptions::new().concurrency(4).rate_limit(2.0, 2);
 
     println!(
-        "Checking {} profiles with {}s delay between requests...\n",
+        "Checking {} URLs, {} at a time, {:?} per second...\n",
         urls_to_check.len(),
-        delay.as_secs()
+        options.max_concurrency(),
+        options.requests_per_second().unwrap_or(f64::INFINITY)
     );
 
-    for (i, url) in urls_to_check.iter().enumerate() {
-        print!("[{}/{}] Checking {}: ", i + 1, urls_to_check.len(), url);
-
-        match validator.is_valid_linkedin_profile_url(url) {
-            Ok(_) => println!("✓ Valid profile"),
-            Err(LinkedInUrlError::ProfileNotFound) => println!("✗ Profile not found"),
-            Err(LinkedInUrlError::AuthenticationRequired) => {
-                println!("⚠ Authentication required - LinkedIn may be rate limiting");
-                println!("    Consider increasing delay between requests or using authentication");
-            }
-            Err(e) => println!("✗ Error: {e}"),
-        }
-
-        // Add delay between requests to avoid rate limiting
-        if i < urls_to_check.len() - 1 {
-            thread::sleep(delay);
-        }
+    // Results arrive as soon as each validation completes
+    let results = validator.validate_stream(futures_util::stream::iter(urls_to_check), options);
+    let mut results = std::pin::pin!(results);
+    while let Some((url, result)) = results.next().await {
+        let symbol = match result.decision {
+            AIDecision::Accept => "✓",
+            AIDecision::Retry => "⚠",
+            AIDecision::Reject => "✗",
+        };
+        println!("{symbol} {url}: {}", result.reason);
     }
 
     println!("\nBatch validation complete!");
-    println!("\nTip: If you're seeing authentication errors, LinkedIn may be detecting");
-    println!("automated requests. Consider:");
-    println!("- Increasing the delay between requests");
-    println!("- Using format validation only when full validation isn't needed");
-    println!("- Implementing proper authentication if available");
+    println!("\nTip: If you're seeing inconclusive results, LinkedIn may be rate limiting.");
+    println!("Consider lowering the rate limit or the concurrency in BatchOptions.");
 }
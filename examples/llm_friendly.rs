@@ -62,6 +62,26 @@ fn main() {
                         println!("ERROR_MESSAGE: {e}");
                         println!("SUGGESTED_ACTION: Use profile URLs in format /in/username");
                     }
+                    LinkedInUrlError::NotCompanyUrl => {
+                        println!("ERROR_TYPE: NOT_COMPANY_URL");
+                        println!("ERROR_MESSAGE: {e}");
+                        println!("SUGGESTED_ACTION: Use company URLs in format /company/name");
+                    }
+                    LinkedInUrlError::LegacyProfileUrl(_) => {
+                        println!("ERROR_TYPE: LEGACY_PROFILE_URL");
+                        println!("ERROR_MESSAGE: {e}");
+                        println!("SUGGESTED_ACTION: Resolve the URL to its /in/ profile URL");
+                    }
+                    LinkedInUrlError::UnresolvedShortLink(_) => {
+                        println!("ERROR_TYPE: SHORT_LINK_UNRESOLVED");
+                        println!("ERROR_MESSAGE: {e}");
+                        println!("SUGGESTED_ACTION: Ask for the full linkedin.com/in/ URL");
+                    }
+                    LinkedInUrlError::InvalidUsername(_) => {
+                        println!("ERROR_TYPE: INVALID_USERNAME");
+                        println!("ERROR_MESSAGE: {e}");
+                        println!("SUGGESTED_ACTION: Check the username for typos and retry");
+                    }
                     LinkedInUrlError::ProfileNotFound => {
                         println!("ERROR_TYPE: PROFILE_NOT_FOUND");
                         println!("ERROR_MESSAGE: {e}");
@@ -74,7 +94,7 @@ fn main() {
                             "SUGGESTED_ACTION: Cannot verify profile existence - LinkedIn requires authentication. Consider using format validation only or implement authentication"
                         );
                     }
-                    LinkedInUrlError::NetworkError(_) => {
+                    LinkedInUrlError::NetworkError(_) | LinkedInUrlError::TransportError(_) => {
                         println!("ERROR_TYPE: NETWORK_ERROR");
                         println!("ERROR_MESSAGE: {e}");
                         println!("SUGGESTED_ACTION: Check network connection and retry");
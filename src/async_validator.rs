@@ -0,0 +1,509 @@
+//! Reusable async validator sharing one HTTP client across requests.
+
+use crate::{
+    AIValidationResult, AsyncReqwestTransport, AsyncTransport, BatchOptions, CachePolicy,
+    DetectionRules, DomainPolicy, IdentityMatch, LinkedInUrlError, LinkedInValidatorBuilder,
+    PersonHint, ProfileSuggestion, RetryPolicy, ThrottlePolicy, ThrottleState, ValidationCache,
+    ValidationOutcome, batch,
+    cache::{self, ResultCache},
+    repair::repair_for,
+    report,
+    retry::Attempts,
+    score_identity,
+    settings::ValidationSettings,
+    suggest::{self, SuggestOptions},
+    throttle::Throttle,
+    validate_company_with_transport, validate_with_transport,
+};
+use futures_util::stream::{self, Stream, StreamExt};
+use once_cell::sync::Lazy;
+use std::sync::{Arc, Mutex};
+use tokio::runtime::{self, Handle};
+
+/// Runtimes that keep their own default validator before the oldest is dropped.
+const MAX_DEFAULT_VALIDATORS: usize = 8;
+
+/// Validators behind the async free functions and `rig_*` helpers, one per runtime.
+///
+/// `reqwest` connections belong to the runtime that opened them, so a client is never
+/// shared across runtimes: callers that create a runtime per test or per call get a fresh
+/// client instead of one whose connections died with an earlier runtime.
+static DEFAULT_ASYNC_VALIDATORS: Lazy<Mutex<Vec<(runtime::Id, AsyncLinkedInValidator)>>> =
+    Lazy::new(|| Mutex::new(Vec::new()));
+
+/// Returns the validator of the current runtime used by the async free functions.
+///
+/// Outside a runtime a new validator is built for the call.
+pub(crate) fn default_async_validator() -> Result<AsyncLinkedInValidator, LinkedInUrlError> {
+    let Ok(handle) = Handle::try_current() else {
+        return AsyncLinkedInValidator::new();
+    };
+    let id = handle.id();
+    let mut validators = DEFAULT_ASYNC_VALIDATORS
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    if let Some((_, validator)) = validators.iter().find(|(runtime, _)| *runtime == id) {
+        return Ok(validator.clone());
+    }
+    let validator = AsyncLinkedInValidator::new()?;
+    if validators.len() == MAX_DEFAULT_VALIDATORS {
+        validators.remove(0);
+    }
+    validators.push((id, validator.clone()));
+    Ok(validator)
+}
+
+/// An async `LinkedIn` profile validator that reuses one HTTP client.
+///
+/// Cloning is cheap and clones share the same connection pool and TLS sessions,
+/// so a single instance can be handed to every task of an agent fleet.
+/// The async free functions ([`validate_linkedin_url_async`](crate::validate_linkedin_url_async),
+/// [`ai_validate_async`](crate::ai_validate_async), the `rig_*` helpers, ...) delegate
+/// to an instance with the default configuration, created once per Tokio runtime.
+///
+/// # Example
+///
+/// ```no_run
+/// use credify::AsyncLinkedInValidator;
+///
+/// # async fn example() {
+/// let validator = AsyncLinkedInValidator::new().expect("Failed to create validator");
+/// match validator.is_valid_linkedin_profile_url("https://www.linkedin.com/in/johndoe").await {
+///     Ok(_) => println!("Valid profile"),
+///     Err(e) => println!("Invalid: {}", e),
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct AsyncLinkedInValidator<T = AsyncReqwestTransport> {
+    transport: Arc<T>,
+    settings: ValidationSettings,
+}
+
+impl<T> Clone for AsyncLinkedInValidator<T> {
+    fn clone(&self) -> Self {
+        Self {
+            transport: Arc::clone(&self.transport),
+            settings: self.settings.clone(),
+        }
+    }
+}
+
+impl AsyncLinkedInValidator {
+    /// Creates a new async validator with the default configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be built.
+    pub fn new() -> Result<Self, LinkedInUrlError> {
+        Ok(Self::with_transport(AsyncReqwestTransport::new()?))
+    }
+
+    /// Creates a validator that sends `user_agent` instead of the default browser user agent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be built.
+    pub fn new_with_user_agent(user_agent: &str) -> Result<Self, LinkedInUrlError> {
+        LinkedInValidatorBuilder::new()
+            .user_agent(user_agent)
+            .build_async()
+    }
+
+    /// Returns a [`LinkedInValidatorBuilder`] for configuring the HTTP client.
+    #[must_use]
+    pub fn builder() -> LinkedInValidatorBuilder {
+        LinkedInValidatorBuilder::new()
+    }
+
+    /// Creates a new validator with default configuration.
+    ///
+    /// This is a convenience method that panics on error.
+    /// For production use, prefer `new()` and handle the error.
+    #[must_use]
+    pub fn new_unchecked() -> Self {
+        Self::new().expect("[INTERNAL_ERROR] Failed to create async LinkedIn validator")
+    }
+}
+
+impl<T: AsyncTransport> AsyncLinkedInValidator<T> {
+    /// Creates a validator that sends its requests through `transport`.
+    pub fn with_transport(transport: T) -> Self {
+        Self {
+            transport: Arc::new(transport),
+            settings: ValidationSettings::new(),
+        }
+    }
+
+    /// Replaces the policy deciding which hosts are accepted as `LinkedIn`.
+    #[must_use]
+    pub fn with_domain_policy(mut self, domain_policy: DomainPolicy) -> Self {
+        self.settings.domain_policy = domain_policy;
+        self
+    }
+
+    /// Sets whether legacy `/pub/` and `/profile/view?id=` URLs are followed to their
+    /// `/in/` profile. When disabled they fail with [`LinkedInUrlError::LegacyProfileUrl`].
+    #[must_use]
+    pub const fn with_legacy_url_resolution(mut self, resolve: bool) -> Self {
+        self.settings.resolve_legacy_urls = resolve;
+        self
+    }
+
+    /// Sets whether `lnkd.in` short links are followed before the profile checks.
+    ///
+    /// See [`LinkedInValidator::with_short_link_resolution`](crate::LinkedInValidator::with_short_link_resolution).
+    #[must_use]
+    pub const fn with_short_link_resolution(mut self, resolve: bool) -> Self {
+        self.settings.resolve_short_links = resolve;
+        self
+    }
+
+    /// Sets how many redirects are followed while resolving a short link. Defaults to 5.
+    #[must_use]
+    pub const fn with_max_short_link_hops(mut self, max_hops: usize) -> Self {
+        self.settings.max_short_link_hops = max_hops;
+        self
+    }
+
+    /// Sets whether [`ai_validate`](Self::ai_validate) repairs malformed input first.
+    ///
+    /// See [`LinkedInValidator::with_url_repair`](crate::LinkedInValidator::with_url_repair).
+    #[must_use]
+    pub const fn with_url_repair(mut self, repair: bool) -> Self {
+        self.settings.repair_urls = repair;
+        self
+    }
+
+    /// Sets whether the public details of served profile pages are read.
+    ///
+    /// See [`LinkedInValidator::with_profile_summary`](crate::LinkedInValidator::with_profile_summary).
+    #[must_use]
+    pub const fn with_profile_summary(mut self, fetch: bool) -> Self {
+        self.settings.fetch_profile_summary = fetch;
+        self
+    }
+
+    /// Replaces the rules deciding whether a response shows, hides or lacks a profile.
+    ///
+    /// See [`LinkedInValidatorBuilder::detection_rules`].
+    #[must_use]
+    pub fn with_detection_rules(mut self, rules: DetectionRules) -> Self {
+        self.settings.detection_rules = Some(Arc::new(rules));
+        self
+    }
+
+    /// Sets the `Accept-Language` header sent with page requests.
+    ///
+    /// See [`LinkedInValidatorBuilder::accept_language`].
+    #[must_use]
+    pub fn with_accept_language(mut self, language: impl Into<String>) -> Self {
+        self.settings.accept_language = Some(language.into());
+        self
+    }
+
+    /// Enables adaptive backoff and the circuit breaker described by `policy`.
+    ///
+    /// Clones of the validator share the throttle state, so one circuit breaker pauses
+    /// every task. See [`LinkedInValidatorBuilder::throttle`].
+    #[must_use]
+    pub fn with_throttle(mut self, policy: ThrottlePolicy) -> Self {
+        self.settings.throttle = Some(Arc::new(Throttle::new(policy)));
+        self
+    }
+
+    /// Returns what the validator has seen of `LinkedIn`'s throttling, if a
+    /// [`ThrottlePolicy`] is set.
+    #[must_use]
+    pub fn throttle_state(&self) -> Option<ThrottleState> {
+        self.settings
+            .throttle
+            .as_ref()
+            .map(|throttle| throttle.state())
+    }
+
+    /// Retries page requests that fail for transient reasons as described by `policy`.
+    ///
+    /// See [`LinkedInValidatorBuilder::retry`].
+    #[must_use]
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.settings.retry = Some(policy);
+        self
+    }
+
+    /// Caches [`ai_validate`](Self::ai_validate) results as described by `policy`.
+    ///
+    /// Clones of the validator share the cache. See [`LinkedInValidatorBuilder::cache`].
+    #[must_use]
+    pub fn with_cache(mut self, policy: CachePolicy) -> Self {
+        self.settings.cache = Some(Arc::new(ResultCache::new(policy)));
+        self
+    }
+
+    /// Caches [`ai_validate`](Self::ai_validate) results in `store` as described by
+    /// `policy`, for example in a [`FileCache`](crate::FileCache) shared with other processes.
+    ///
+    /// See [`LinkedInValidatorBuilder::cache_store`].
+    #[must_use]
+    pub fn with_cache_store(
+        mut self,
+        policy: CachePolicy,
+        store: impl ValidationCache + 'static,
+    ) -> Self {
+        self.settings.cache = Some(Arc::new(ResultCache::with_store(policy, Arc::new(store))));
+        self
+    }
+
+    /// Forgets every cached result. Does nothing if no [`CachePolicy`] is set.
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.settings.cache {
+            cache.clear();
+        }
+    }
+
+    pub(crate) fn with_settings(mut self, settings: ValidationSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    /// Returns the transport used by this validator.
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// Returns the policy deciding which hosts are accepted as `LinkedIn`.
+    pub const fn domain_policy(&self) -> &DomainPolicy {
+        &self.settings.domain_policy
+    }
+
+    /// Validates a `LinkedIn` profile URL by checking format and existence.
+    ///
+    /// This is the async counterpart of
+    /// [`LinkedInValidator::is_valid_linkedin_profile_url`](crate::LinkedInValidator::is_valid_linkedin_profile_url).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The URL format is invalid
+    /// - The URL is not from `LinkedIn` domain
+    /// - The URL is not a profile URL
+    /// - Network request fails
+    /// - The profile doesn't exist (404)
+    /// - `LinkedIn` requires authentication
+    pub async fn is_valid_linkedin_profile_url(&self, url: &str) -> Result<bool, LinkedInUrlError> {
+        self.validate(url).await?.status.into_result()
+    }
+
+    /// Checks a `LinkedIn` profile URL and reports what `LinkedIn` said about it.
+    ///
+    /// This is the async counterpart of
+    /// [`LinkedInValidator::validate`](crate::LinkedInValidator::validate).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is not a `LinkedIn` profile URL or the request fails.
+    pub async fn validate(&self, url: &str) -> Result<ValidationOutcome, LinkedInUrlError> {
+        validate_with_transport(
+            self.transport.as_ref(),
+            &self.settings,
+            url,
+            &mut Attempts::new(),
+        )
+        .await
+    }
+
+    /// Checks a `LinkedIn` company page URL and reports what `LinkedIn` said about it.
+    ///
+    /// This is the async counterpart of
+    /// [`LinkedInValidator::validate_company_url`](crate::LinkedInValidator::validate_company_url).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the URL is not a `LinkedIn` company page URL or the request fails.
+    pub async fn validate_company_url(
+        &self,
+        url: &str,
+    ) -> Result<ValidationOutcome, LinkedInUrlError> {
+        validate_company_with_transport(
+            self.transport.as_ref(),
+            &self.settings,
+            url,
+            &mut Attempts::new(),
+        )
+        .await
+    }
+
+    /// Validates a URL and returns the structured result for AI agents.
+    ///
+    /// See [`ai_validate_async`](crate::ai_validate_async).
+    pub async fn ai_validate(&self, url: &str) -> AIValidationResult {
+        let repaired = repair_for(url, &self.settings);
+        let url = repaired
+            .as_ref()
+            .map_or(url, |repaired| repaired.url.as_str());
+        let cached = cache::lookup_key(url, &self.settings);
+        if let Some((cache, key)) = &cached {
+            if let Some(hit) = cache.hit_async(key).await {
+                return report::after_repair(hit, repaired);
+            }
+        }
+        let mut attempts = Attempts::new();
+        let validation =
+            validate_with_transport(self.transport.as_ref(), &self.settings, url, &mut attempts);
+        let mut result = report::ai_result_async(url, &self.settings, validation).await;
+        result.metadata.attempts = attempts.reported();
+        if let Some((cache, key)) = cached {
+            cache.insert_async(&key, &result).await;
+        }
+        report::after_repair(result, repaired)
+    }
+
+    /// Validates every URL of `urls` and yields `(input, result)` pairs as they complete.
+    ///
+    /// Runs at most [`BatchOptions::max_concurrency`] validations at a time under the
+    /// options' rate limit. Inputs with the same canonical profile URL are validated once,
+    /// and each of them is yielded with the shared result. The stream keeps the last
+    /// [`BatchOptions::remembered_results`] finished results, so duplicates further apart
+    /// are validated again; set a cache on the validator to avoid that.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use credify::{AsyncLinkedInValidator, BatchOptions};
+    /// use futures_util::StreamExt;
+    ///
+    /// # async fn example() {
+    /// let validator = AsyncLinkedInValidator::new().expect("Failed to create validator");
+    /// let urls = futures_util::stream::iter(vec![
+    ///     "https://www.linkedin.com/in/johndoe",
+    ///     "https://uk.linkedin.com/in/JohnDoe/",
+    /// ]);
+    /// let mut results = std::pin::pin!(validator.validate_stream(urls, BatchOptions::new()));
+    /// while let Some((url, result)) = results.next().await {
+    ///     println!("{url}: {:?}", result.decision);
+    /// }
+    /// # }
+    /// ```
+    pub fn validate_stream<S>(
+        &self,
+        urls: S,
+        options: BatchOptions,
+    ) -> impl Stream<Item = (String, AIValidationResult)>
+    where
+        S: Stream,
+        S::Item: Into<String>,
+    {
+        batch::ai_result_stream(urls.map(Into::into), options, move |url| async move {
+            self.ai_validate(&url).await
+        })
+    }
+
+    /// Validates every URL of `urls` and returns `(input, result)` pairs in completion order.
+    ///
+    /// Every finished result is kept for the duplicates that follow, whatever
+    /// [`BatchOptions::remembered_results`] says. See [`validate_stream`](Self::validate_stream).
+    pub async fn validate_batch<I>(
+        &self,
+        urls: I,
+        options: BatchOptions,
+    ) -> Vec<(String, AIValidationResult)>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.validate_stream(stream::iter(urls), options.remember_results(usize::MAX))
+            .collect()
+            .await
+    }
+
+    /// Validates a URL and returns the AI result serialized as JSON.
+    ///
+    /// See [`ai_validate_json_async`](crate::ai_validate_json_async).
+    pub async fn ai_validate_json(&self, url: &str) -> String {
+        report::ai_json(&self.ai_validate(url).await)
+    }
+
+    /// Validates a URL and returns the verbose report for LLM consumption.
+    ///
+    /// See [`validate_for_llm_async`](crate::validate_for_llm_async).
+    pub async fn validate_for_llm(&self, url: &str) -> String {
+        let validation = self.validate(url).await;
+        report::llm_report(url, validation)
+    }
+
+    /// Validates a URL and scores whether the profile belongs to `expected`.
+    ///
+    /// See [`LinkedInValidator::match_profile`](crate::LinkedInValidator::match_profile).
+    pub async fn match_profile(&self, expected: &PersonHint, url: &str) -> IdentityMatch {
+        score_identity(expected, self.ai_validate(url).await)
+    }
+
+    /// Generates candidate profile URLs for a person and validates the highest ranked ones
+    /// one after the other.
+    ///
+    /// See [`suggest_profile_urls_async`](crate::suggest_profile_urls_async).
+    pub async fn suggest_profile_urls(
+        &self,
+        first: &str,
+        last: &str,
+        options: &SuggestOptions,
+    ) -> Vec<ProfileSuggestion> {
+        let mut suggestions = suggest::candidates(first, last, options);
+        for suggestion in suggestions.iter_mut().take(options.max_validations()) {
+            suggestion.validation = Some(self.ai_validate(&suggestion.url).await);
+        }
+        suggestions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HttpResponse, ScriptedTransport};
+
+    fn assert_shareable<V: Clone + Send + Sync + 'static>() {}
+
+    #[test]
+    fn test_validator_is_shareable() {
+        assert_shareable::<AsyncLinkedInValidator>();
+        assert_shareable::<AsyncLinkedInValidator<ScriptedTransport>>();
+    }
+
+    #[tokio::test]
+    async fn test_clones_share_transport() {
+        let transport = ScriptedTransport::new();
+        transport.respond_to(
+            "https://www.linkedin.com/in/johndoe",
+            HttpResponse::new(200, "https://www.linkedin.com/in/johndoe"),
+        );
+        let validator = AsyncLinkedInValidator::with_transport(transport);
+        let clone = validator.clone();
+
+        let result = clone
+            .ai_validate("https://www.linkedin.com/in/johndoe")
+            .await;
+        assert!(result.is_valid);
+        assert_eq!(result.username.as_deref(), Some("johndoe"));
+        assert_eq!(validator.transport().requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_default_validator_is_reused() {
+        let first = default_async_validator().expect("default validator");
+        let second = default_async_validator().expect("default validator");
+        assert!(Arc::ptr_eq(&first.transport, &second.transport));
+    }
+
+    #[test]
+    fn test_default_validator_is_per_runtime() {
+        let validator = || {
+            tokio::runtime::Builder::new_current_thread()
+                .build()
+                .expect("runtime")
+                .block_on(async { default_async_validator().expect("default validator") })
+        };
+        let first = validator();
+        let second = validator();
+        assert!(!Arc::ptr_eq(&first.transport, &second.transport));
+    }
+}
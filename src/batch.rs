@@ -0,0 +1,335 @@
+//! Concurrent, rate limited validation of many URLs.
+
+use crate::{AIValidationResult, normalize_profile_url};
+use futures_util::future::FutureExt;
+use futures_util::stream::{Stream, StreamExt};
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// Validations run at the same time by default.
+const DEFAULT_CONCURRENCY: usize = 8;
+
+/// Validations started per second by default.
+const DEFAULT_REQUESTS_PER_SECOND: f64 = 5.0;
+
+/// Validations that may start back to back before the rate limit applies, by default.
+const DEFAULT_BURST: u32 = 5;
+
+/// Finished results a stream keeps for later duplicates, by default.
+const DEFAULT_REMEMBERED_RESULTS: usize = 10_000;
+
+/// How a batch of URLs is validated.
+///
+/// The rate limit is a token bucket: up to `burst` validations start immediately, then
+/// `requests_per_second` more are allowed every second. A validation may send a second
+/// request when `LinkedIn` answers with its bot detection status.
+///
+/// Inputs with the same canonical profile URL are validated once. A stream keeps the last
+/// `remember_results` finished results to answer later duplicates, so an endless stream
+/// does not grow without bound; a batch keeps every result.
+///
+/// # Example
+///
+/// ```
+/// use credify::BatchOptions;
+///
+/// let options = BatchOptions::new().concurrency(16).rate_limit(10.0, 20);
+/// assert_eq!(options.max_concurrency(), 16);
+///
+/// let unlimited = BatchOptions::new().unlimited();
+/// assert_eq!(unlimited.requests_per_second(), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BatchOptions {
+    concurrency: usize,
+    requests_per_second: Option<f64>,
+    burst: u32,
+    remembered_results: usize,
+}
+
+impl Default for BatchOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BatchOptions {
+    /// Creates the default options: 8 validations at a time, at most 5 started per second
+    /// with bursts of 5, and streams remembering 10,000 results for later duplicates.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            concurrency: DEFAULT_CONCURRENCY,
+            requests_per_second: Some(DEFAULT_REQUESTS_PER_SECOND),
+            burst: DEFAULT_BURST,
+            remembered_results: DEFAULT_REMEMBERED_RESULTS,
+        }
+    }
+
+    /// Sets how many validations run at the same time. Values below 1 are treated as 1.
+    #[must_use]
+    pub const fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = if concurrency == 0 { 1 } else { concurrency };
+        self
+    }
+
+    /// Limits validations to `requests_per_second`, allowing `burst` to start back to back.
+    ///
+    /// A rate that is not a positive number removes the limit; a burst below 1 is treated
+    /// as 1.
+    #[must_use]
+    pub fn rate_limit(mut self, requests_per_second: f64, burst: u32) -> Self {
+        self.requests_per_second = Some(requests_per_second).filter(|rate| *rate > 0.0);
+        self.burst = burst.max(1);
+        self
+    }
+
+    /// Removes the rate limit, leaving only the concurrency bound.
+    #[must_use]
+    pub const fn unlimited(mut self) -> Self {
+        self.requests_per_second = None;
+        self
+    }
+
+    /// Sets how many finished results a stream keeps to answer later duplicates, dropping
+    /// the oldest beyond that. With 0 only duplicates still being validated are merged.
+    ///
+    /// Batches keep every result, since they return them all anyway.
+    #[must_use]
+    pub const fn remember_results(mut self, max: usize) -> Self {
+        self.remembered_results = max;
+        self
+    }
+
+    /// Returns how many finished results a stream keeps for later duplicates.
+    #[must_use]
+    pub const fn remembered_results(&self) -> usize {
+        self.remembered_results
+    }
+
+    /// Returns how many validations run at the same time.
+    #[must_use]
+    pub const fn max_concurrency(&self) -> usize {
+        self.concurrency
+    }
+
+    /// Returns the rate limit in validations per second, if there is one.
+    #[must_use]
+    pub const fn requests_per_second(&self) -> Option<f64> {
+        self.requests_per_second
+    }
+}
+
+/// Validates every URL of `urls` with `validate` and yields the results as they complete.
+///
+/// Inputs with the same canonical profile URL share one validation, and each of them is
+/// yielded with its result. Up to [`BatchOptions::remembered_results`] finished results
+/// answer later duplicates.
+pub(crate) fn ai_result_stream<S, F, Fut>(
+    urls: S,
+    options: BatchOptions,
+    validate: F,
+) -> impl Stream<Item = (String, AIValidationResult)>
+where
+    S: Stream<Item = String>,
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = AIValidationResult>,
+{
+    let limiter = options
+        .requests_per_second
+        .map(|rate| Arc::new(TokenBucket::new(rate, options.burst)));
+    let capacity = options.remembered_results;
+    let seen = Arc::new(Mutex::new(Seen::new()));
+
+    urls.map(move |input| {
+        let key = dedup_key(&input);
+        let mut known = seen.lock().unwrap_or_else(PoisonError::into_inner);
+        let lookup = match known.finished.get(&key) {
+            Some(result) => Lookup::Finished(Box::new(result.clone())),
+            None => Lookup::Running(
+                known
+                    .running
+                    .entry(key.clone())
+                    .or_insert_with(|| {
+                        let limiter = limiter.clone();
+                        let validation = validate(input.clone());
+                        async move {
+                            if let Some(limiter) = limiter {
+                                limiter.acquire().await;
+                            }
+                            validation.await
+                        }
+                        .shared()
+                    })
+                    .clone(),
+            ),
+        };
+        drop(known);
+        let seen = Arc::clone(&seen);
+        async move {
+            let validation = match lookup {
+                Lookup::Finished(result) => return (input, *result),
+                Lookup::Running(validation) => validation,
+            };
+            let result = validation.clone().await;
+            // The first input to finish moves the result from running to finished
+            let mut known = seen.lock().unwrap_or_else(PoisonError::into_inner);
+            if known
+                .running
+                .get(&key)
+                .is_some_and(|current| validation.ptr_eq(current))
+            {
+                known.running.remove(&key);
+                known.remember(key, &result, capacity);
+            }
+            (input, result)
+        }
+    })
+    .buffer_unordered(options.concurrency)
+}
+
+/// What a stream knows about the canonical URLs it has seen.
+struct Seen<V> {
+    /// Validations still running
+    running: HashMap<String, V>,
+    /// Finished results kept for later duplicates
+    finished: HashMap<String, AIValidationResult>,
+    /// Keys of `finished`, oldest first
+    order: VecDeque<String>,
+}
+
+impl<V> Seen<V> {
+    fn new() -> Self {
+        Self {
+            running: HashMap::new(),
+            finished: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// Keeps `result` for later duplicates of `key`, dropping the oldest beyond `capacity`.
+    fn remember(&mut self, key: String, result: &AIValidationResult, capacity: usize) {
+        if capacity == 0 {
+            return;
+        }
+        if self.order.len() >= capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.finished.remove(&oldest);
+            }
+        }
+        self.finished.insert(key.clone(), result.clone());
+        self.order.push_back(key);
+    }
+}
+
+/// Whether an input's canonical URL already has a result or a validation to wait on.
+enum Lookup<V> {
+    Finished(Box<AIValidationResult>),
+    Running(V),
+}
+
+/// Inputs that normalize to the same profile are validated once.
+fn dedup_key(input: &str) -> String {
+    normalize_profile_url(input)
+        .map_or_else(|_| input.to_string(), |canonical| canonical.into_string())
+}
+
+/// Token bucket limiting how often validations start.
+#[derive(Debug)]
+struct TokenBucket {
+    rate: f64,
+    burst: f64,
+    state: Mutex<BucketState>,
+}
+
+#[derive(Debug)]
+struct BucketState {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl TokenBucket {
+    fn new(rate: f64, burst: u32) -> Self {
+        let burst = f64::from(burst);
+        Self {
+            rate,
+            burst,
+            state: Mutex::new(BucketState {
+                tokens: burst,
+                refilled_at: Instant::now(),
+            }),
+        }
+    }
+
+    /// Waits until a token is available and takes it.
+    async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+                let now = Instant::now();
+                let elapsed = now.duration_since(state.refilled_at).as_secs_f64();
+                state.tokens = elapsed.mul_add(self.rate, state.tokens).min(self.burst);
+                state.refilled_at = now;
+                if state.tokens >= 1.0 {
+                    state.tokens -= 1.0;
+                    return;
+                }
+                Duration::from_secs_f64((1.0 - state.tokens) / self.rate)
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_token_bucket_spaces_out_requests() {
+        let bucket = TokenBucket::new(20.0, 2);
+        let start = Instant::now();
+        for _ in 0..4 {
+            bucket.acquire().await;
+        }
+        // Two tokens are available at once, the other two take 50ms each
+        assert!(start.elapsed() >= Duration::from_millis(90));
+    }
+
+    #[test]
+    fn test_options() {
+        let options = BatchOptions::new().concurrency(0).rate_limit(-1.0, 0);
+        assert_eq!(options.max_concurrency(), 1);
+        assert_eq!(options.requests_per_second(), None);
+        assert_eq!(options.burst, 1);
+    }
+
+    #[test]
+    fn test_dedup_key() {
+        assert_eq!(
+            dedup_key("https://uk.linkedin.com/in/Jane-Doe/?trk=x"),
+            dedup_key("https://www.linkedin.com/in/jane-doe")
+        );
+        assert_eq!(dedup_key("not a url"), "not a url");
+    }
+
+    #[test]
+    fn test_seen_keeps_the_newest_results() {
+        let result =
+            crate::report::ai_result("not a url", &crate::ValidationSettings::new(), || {
+                unreachable!("format checks fail")
+            });
+        let mut seen = Seen::<()>::new();
+        for key in ["a", "b", "c"] {
+            seen.remember(key.to_string(), &result, 2);
+        }
+        assert!(!seen.finished.contains_key("a"));
+        assert!(seen.finished.contains_key("b") && seen.finished.contains_key("c"));
+
+        let mut forgetful = Seen::<()>::new();
+        forgetful.remember("a".to_string(), &result, 0);
+        assert!(forgetful.finished.is_empty());
+    }
+}
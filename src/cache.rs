@@ -0,0 +1,413 @@
+//! Caching of AI validation results.
+
+use crate::profile_url::ProfileTarget;
+use crate::{
+    AIDecision, AIValidationResult, HostVariant, parse_profile_url, settings::ValidationSettings,
+};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// Results kept by default before the least recently used one is evicted.
+const DEFAULT_CAPACITY: usize = 1024;
+
+/// How long a validator remembers its validation results.
+///
+/// Results are keyed by canonical profile URL, so `https://uk.linkedin.com/in/Jane-Doe/`
+/// and `https://www.linkedin.com/in/jane-doe` share one entry. Each kind of result has its
+/// own time to live:
+///
+/// - positive: the profile exists
+/// - negative: the profile page answered 404
+/// - inconclusive: an authwall, a rate limit or a network error kept the answer hidden
+///
+/// A time to live of zero leaves that kind of result uncached. Once the in-memory cache
+/// holds `capacity` results, the least recently used one makes room for the next. Results
+/// are kept in a [`MemoryCache`] unless another [`ValidationCache`], such as a
+/// [`FileCache`](crate::FileCache), is given.
+///
+/// # Example
+///
+/// ```
+/// use credify::CachePolicy;
+/// use std::time::Duration;
+///
+/// let policy = CachePolicy::new()
+///     .capacity(500)
+///     .negative_ttl(Duration::from_secs(600))
+///     .inconclusive_ttl(Duration::ZERO);
+/// assert_eq!(policy.max_entries(), 500);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CachePolicy {
+    capacity: usize,
+    positive_ttl: Duration,
+    negative_ttl: Duration,
+    inconclusive_ttl: Duration,
+}
+
+impl Default for CachePolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CachePolicy {
+    /// Creates the default policy: 1024 results, positive ones kept for a day, negative
+    /// ones for an hour and inconclusive ones for 5 minutes.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            capacity: DEFAULT_CAPACITY,
+            positive_ttl: Duration::from_secs(24 * 60 * 60),
+            negative_ttl: Duration::from_secs(60 * 60),
+            inconclusive_ttl: Duration::from_secs(5 * 60),
+        }
+    }
+
+    /// Sets how many results the in-memory cache keeps. Values below 1 are treated as 1.
+    #[must_use]
+    pub const fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = if capacity == 0 { 1 } else { capacity };
+        self
+    }
+
+    /// Sets how long results for existing profiles are kept.
+    #[must_use]
+    pub const fn positive_ttl(mut self, ttl: Duration) -> Self {
+        self.positive_ttl = ttl;
+        self
+    }
+
+    /// Sets how long results for missing (404) profiles are kept.
+    #[must_use]
+    pub const fn negative_ttl(mut self, ttl: Duration) -> Self {
+        self.negative_ttl = ttl;
+        self
+    }
+
+    /// Sets how long authwall, rate limit and network error results are kept.
+    #[must_use]
+    pub const fn inconclusive_ttl(mut self, ttl: Duration) -> Self {
+        self.inconclusive_ttl = ttl;
+        self
+    }
+
+    /// Returns how many results the in-memory cache keeps.
+    #[must_use]
+    pub const fn max_entries(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns how long `result` may be served from the cache, or `None` if it is not cached.
+    fn ttl(&self, result: &AIValidationResult) -> Option<Duration> {
+        let ttl = match (result.decision, result.metadata.error_type.as_deref()) {
+            (AIDecision::Accept, None) => self.positive_ttl,
+            (AIDecision::Reject, Some("NOT_FOUND")) => self.negative_ttl,
+            (AIDecision::Accept, Some("AUTH_REQUIRED")) | (AIDecision::Retry, _) => {
+                self.inconclusive_ttl
+            }
+            _ => return None,
+        };
+        Some(ttl).filter(|ttl| !ttl.is_zero())
+    }
+}
+
+/// Storage for cached validation results.
+///
+/// Keys are canonical profile URLs. Results are stored already marked as cached, and a
+/// store must not return a result once its time to live has passed. Stores are best
+/// effort: a result that cannot be stored or read is simply validated again.
+///
+/// Stores may block: async validators call them on Tokio's blocking thread pool.
+pub trait ValidationCache: fmt::Debug + Send + Sync {
+    /// Returns the result stored under `key`, unless there is none or it has expired.
+    fn get(&self, key: &str) -> Option<AIValidationResult>;
+
+    /// Stores `result` under `key` for `ttl`, replacing any earlier result.
+    fn insert(&self, key: &str, result: &AIValidationResult, ttl: Duration);
+
+    /// Forgets every stored result.
+    fn clear(&self);
+}
+
+impl<C: ValidationCache + ?Sized> ValidationCache for Arc<C> {
+    fn get(&self, key: &str) -> Option<AIValidationResult> {
+        (**self).get(key)
+    }
+
+    fn insert(&self, key: &str, result: &AIValidationResult, ttl: Duration) {
+        (**self).insert(key, result, ttl);
+    }
+
+    fn clear(&self) {
+        (**self).clear();
+    }
+}
+
+/// In-process cache evicting the least recently used result beyond its capacity.
+#[derive(Debug)]
+pub struct MemoryCache {
+    capacity: usize,
+    entries: Mutex<Entries>,
+}
+
+#[derive(Debug, Default)]
+struct Entries {
+    by_key: HashMap<String, Entry>,
+    /// Keys by the tick they were last used at, least recent first
+    by_use: BTreeMap<u64, String>,
+    tick: u64,
+}
+
+#[derive(Debug)]
+struct Entry {
+    result: AIValidationResult,
+    expires_at: Instant,
+    used_at: u64,
+}
+
+impl Entries {
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.by_key.remove(key) {
+            self.by_use.remove(&entry.used_at);
+        }
+    }
+}
+
+impl MemoryCache {
+    /// Creates a cache holding at most `capacity` results. Values below 1 are treated as 1.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: Mutex::new(Entries::default()),
+        }
+    }
+
+    /// Returns how many results are stored, including expired ones not yet evicted.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().by_key.len()
+    }
+
+    /// Returns `true` if no result is stored.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Entries> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl ValidationCache for MemoryCache {
+    fn get(&self, key: &str) -> Option<AIValidationResult> {
+        let mut entries = self.lock();
+        let tick = entries.next_tick();
+        let entry = entries.by_key.get_mut(key)?;
+        if entry.expires_at <= Instant::now() {
+            entries.remove(key);
+            return None;
+        }
+        let previous = std::mem::replace(&mut entry.used_at, tick);
+        let result = entry.result.clone();
+        entries.by_use.remove(&previous);
+        entries.by_use.insert(tick, key.to_string());
+        Some(result)
+    }
+
+    fn insert(&self, key: &str, result: &AIValidationResult, ttl: Duration) {
+        let mut entries = self.lock();
+        entries.remove(key);
+        while entries.by_key.len() >= self.capacity {
+            let Some((_, oldest)) = entries.by_use.pop_first() else {
+                break;
+            };
+            entries.by_key.remove(&oldest);
+        }
+        let used_at = entries.next_tick();
+        entries.by_use.insert(used_at, key.to_string());
+        entries.by_key.insert(
+            key.to_string(),
+            Entry {
+                result: result.clone(),
+                expires_at: Instant::now() + ttl,
+                used_at,
+            },
+        );
+    }
+
+    fn clear(&self) {
+        *self.lock() = Entries::default();
+    }
+}
+
+/// The cache of a validator, shared by its clones.
+#[derive(Debug)]
+pub(crate) struct ResultCache {
+    policy: CachePolicy,
+    store: Arc<dyn ValidationCache>,
+}
+
+impl ResultCache {
+    /// Creates a cache keeping results in a [`MemoryCache`] sized by `policy`.
+    pub(crate) fn new(policy: CachePolicy) -> Self {
+        Self::with_store(policy, Arc::new(MemoryCache::new(policy.capacity)))
+    }
+
+    pub(crate) fn with_store(policy: CachePolicy, store: Arc<dyn ValidationCache>) -> Self {
+        Self { policy, store }
+    }
+
+    /// Returns the cached result for `key`, unless it has expired.
+    pub(crate) fn get(&self, key: &str) -> Option<AIValidationResult> {
+        self.store.get(key)
+    }
+
+    /// Returns the cached result for the input `key` was made from.
+    ///
+    /// The result may have been stored for another spelling of the profile URL, so the
+    /// metadata describing the input is replaced: its host, the time of this call and no
+    /// page requests.
+    pub(crate) fn hit(&self, key: &CacheKey) -> Option<AIValidationResult> {
+        let mut result = self.get(&key.canonical_url)?;
+        result.metadata.host_variant = Some(key.host_variant.clone());
+        result.metadata.timestamp = chrono::Utc::now().to_rfc3339();
+        result.metadata.attempts = None;
+        Some(result)
+    }
+
+    /// [`hit`](Self::hit) for async callers.
+    ///
+    /// Stores such as [`FileCache`](crate::FileCache) block on file I/O and locks, so the
+    /// store is read on Tokio's blocking thread pool instead of the calling worker.
+    pub(crate) async fn hit_async(self: &Arc<Self>, key: &CacheKey) -> Option<AIValidationResult> {
+        let (cache, key) = (Arc::clone(self), key.clone());
+        tokio::task::spawn_blocking(move || cache.hit(&key))
+            .await
+            .ok()
+            .flatten()
+    }
+
+    /// [`insert`](Self::insert) for async callers, writing on Tokio's blocking thread pool.
+    pub(crate) async fn insert_async(
+        self: &Arc<Self>,
+        key: &CacheKey,
+        result: &AIValidationResult,
+    ) {
+        let (cache, key, result) = (Arc::clone(self), key.canonical_url.clone(), result.clone());
+        // The cache is best effort: a failed write is validated again next time
+        let _ = tokio::task::spawn_blocking(move || cache.insert(&key, &result)).await;
+    }
+
+    /// Stores `result` under `key`, marked as cached, if its kind of result is cached.
+    pub(crate) fn insert(&self, key: &str, result: &AIValidationResult) {
+        let Some(ttl) = self.policy.ttl(result) else {
+            return;
+        };
+        let mut cached = result.clone();
+        cached.metadata.cached = true;
+        cached.metadata.cached_at = Some(chrono::Utc::now().to_rfc3339());
+        self.store.insert(key, &cached, ttl);
+    }
+
+    /// Forgets every cached result.
+    pub(crate) fn clear(&self) {
+        self.store.clear();
+    }
+}
+
+/// Where a profile URL's result is cached, and what the URL says about itself.
+#[derive(Debug, Clone)]
+pub(crate) struct CacheKey {
+    /// The canonical profile URL results are stored under
+    pub(crate) canonical_url: String,
+    /// The host of the input URL
+    host_variant: HostVariant,
+}
+
+/// Returns the cache of `settings` and the key `url` is cached under, if results are cached
+/// and `url` is a profile URL.
+pub(crate) fn lookup_key<'a>(
+    url: &str,
+    settings: &'a ValidationSettings,
+) -> Option<(&'a Arc<ResultCache>, CacheKey)> {
+    let cache = settings.cache.as_ref()?;
+    match parse_profile_url(url, settings).ok()? {
+        (ProfileTarget::Modern(profile), host_variant) => Some((
+            cache,
+            CacheKey {
+                canonical_url: profile.canonical.into_string(),
+                host_variant,
+            },
+        )),
+        (ProfileTarget::Legacy(_), _) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report;
+
+    fn result(url: &str) -> AIValidationResult {
+        report::ai_result(url, &ValidationSettings::new(), || {
+            Err(crate::LinkedInUrlError::TransportError(
+                "connection reset".to_string(),
+            ))
+        })
+    }
+
+    #[test]
+    fn test_results_are_marked_cached() {
+        let cache = ResultCache::new(CachePolicy::new());
+        let fresh = result("https://www.linkedin.com/in/johndoe");
+        cache.insert("a", &fresh);
+
+        let hit = cache.get("a").expect("result is cached");
+        assert!(hit.metadata.cached && hit.metadata.cached_at.is_some());
+        assert!(!fresh.metadata.cached);
+        assert!(cache.get("b").is_none());
+    }
+
+    #[test]
+    fn test_least_recently_used_is_evicted() {
+        let cache = MemoryCache::new(2);
+        let fresh = result("https://www.linkedin.com/in/johndoe");
+        let ttl = Duration::from_secs(60);
+        cache.insert("a", &fresh, ttl);
+        cache.insert("b", &fresh, ttl);
+        assert!(cache.get("a").is_some());
+        cache.insert("c", &fresh, ttl);
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("b").is_none());
+        assert!(cache.get("c").is_some());
+
+        cache.insert("d", &fresh, Duration::ZERO);
+        assert!(cache.get("d").is_none());
+    }
+
+    #[test]
+    fn test_zero_ttl_is_not_cached() {
+        let store = Arc::new(MemoryCache::new(10));
+        let cache = ResultCache::with_store(
+            CachePolicy::new().inconclusive_ttl(Duration::ZERO),
+            store.clone(),
+        );
+        cache.insert("a", &result("https://www.linkedin.com/in/johndoe"));
+        assert!(store.is_empty());
+
+        let invalid = result("not a url");
+        assert_eq!(CachePolicy::new().ttl(&invalid), None);
+    }
+}
@@ -0,0 +1,108 @@
+//! Response classification shared by every validation entry point.
+//!
+//! Both transports feed their responses into the validator's
+//! [`DetectionRules`](crate::DetectionRules), so a fix to the 404/authwall detection lands
+//! in the sync, async, AI and LLM APIs at once.
+
+use crate::LinkedInUrlError;
+use crate::rules::DEFAULT_RULES;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Status code `LinkedIn` uses for bot detection and rate limiting.
+pub(crate) const BOT_DETECTION_STATUS: u16 = 999;
+
+/// Cookie sent when retrying a request that was answered with a 999.
+pub(crate) const BYPASS_COOKIE: &str = "sl=v=1&1";
+
+/// What a `LinkedIn` response says about the requested profile.
+///
+/// Only [`Exists`](Self::Exists) and [`NotFound`](Self::NotFound) are conclusive; every
+/// other variant means `LinkedIn` did not let us see the profile page.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProfileStatus {
+    /// The profile page was served
+    Exists,
+    /// `LinkedIn` hid the profile, but its response suggests the profile exists
+    LikelyExists {
+        /// Why the profile is believed to exist
+        reason: String,
+    },
+    /// `LinkedIn` reported that the profile does not exist
+    NotFound,
+    /// `LinkedIn` is rate limiting requests
+    RateLimited {
+        /// How long `LinkedIn` asked us to wait, if it said so
+        retry_after: Option<Duration>,
+    },
+    /// `LinkedIn` refused to serve the request
+    Blocked,
+    /// The response could not be interpreted
+    Unknown,
+}
+
+impl ProfileStatus {
+    /// Returns `true` if the profile exists or is believed to exist.
+    #[must_use]
+    pub const fn is_likely_valid(&self) -> bool {
+        matches!(self, Self::Exists | Self::LikelyExists { .. })
+    }
+
+    /// Returns `true` if the status settles whether the profile exists.
+    #[must_use]
+    pub const fn is_conclusive(&self) -> bool {
+        matches!(self, Self::Exists | Self::NotFound)
+    }
+
+    /// Converts the status into the `Result` shape used by the traditional API.
+    ///
+    /// Inconclusive statuses map to [`LinkedInUrlError::AuthenticationRequired`].
+    pub(crate) fn into_result(self) -> Result<bool, LinkedInUrlError> {
+        match self {
+            Self::Exists => Ok(true),
+            Self::NotFound => Err(LinkedInUrlError::ProfileNotFound),
+            Self::LikelyExists { .. }
+            | Self::RateLimited { .. }
+            | Self::Blocked
+            | Self::Unknown => Err(LinkedInUrlError::AuthenticationRequired),
+        }
+    }
+}
+
+/// Classifies a `LinkedIn` profile response.
+///
+/// This is a pure function: it looks only at its arguments, which makes it easy to
+/// test and to reuse with responses obtained by other means. It applies the built-in
+/// [`DetectionRules`](crate::DetectionRules); validators can be given other rules.
+///
+/// # Arguments
+///
+/// * `status` - HTTP status code of the final response
+/// * `final_url` - URL of the response after redirects were followed
+/// * `headers` - Response headers (used for `Location` and `Retry-After`)
+/// * `body` - Response body
+///
+/// # Example
+///
+/// ```
+/// use credify::{ProfileStatus, classify_response};
+///
+/// let status = classify_response(200, "https://www.linkedin.com/404/", &[], "");
+/// assert_eq!(status, ProfileStatus::NotFound);
+///
+/// let status = classify_response(200, "https://www.linkedin.com/in/johndoe", &[], "<h1>John Doe</h1>");
+/// assert_eq!(status, ProfileStatus::Exists);
+///
+/// let headers = vec![("Retry-After".to_string(), "30".to_string())];
+/// let status = classify_response(429, "https://www.linkedin.com/in/johndoe", &headers, "");
+/// assert!(matches!(status, ProfileStatus::RateLimited { retry_after: Some(_) }));
+/// ```
+#[must_use]
+pub fn classify_response(
+    status: u16,
+    final_url: &str,
+    headers: &[(String, String)],
+    body: &str,
+) -> ProfileStatus {
+    DEFAULT_RULES.classify(status, final_url, headers, body)
+}
@@ -0,0 +1,92 @@
+//! Canonical form of `LinkedIn` company page URLs.
+
+use crate::LinkedInUrlError;
+use percent_encoding::percent_decode_str;
+use url::Url;
+
+/// Host and path prefix used by every canonical company page URL.
+const CANONICAL_PREFIX: &str = "https://www.linkedin.com/company/";
+
+/// Longest company slug accepted, in characters.
+const MAX_SLUG_CHARS: usize = 100;
+
+/// A company page URL reduced to the page it belongs to.
+#[derive(Debug, Clone)]
+pub(crate) struct CompanyPath {
+    /// `https://www.linkedin.com/company/<slug>`
+    pub(crate) canonical: String,
+    /// Percent-decoded, lowercased slug or numeric company id
+    pub(crate) slug: String,
+}
+
+/// Parses the path of a URL whose host was already verified as `LinkedIn`.
+///
+/// Tabs such as `/company/<slug>/about/` or `/company/<slug>/jobs/` resolve to the
+/// company page itself.
+pub(crate) fn parse_company_path(url: &Url) -> Result<CompanyPath, LinkedInUrlError> {
+    let segments: Vec<&str> = url
+        .path_segments()
+        .map(|segments| segments.filter(|segment| !segment.is_empty()).collect())
+        .unwrap_or_default();
+    let ["company", slug, ..] = segments.as_slice() else {
+        return Err(LinkedInUrlError::NotCompanyUrl);
+    };
+
+    let slug = percent_decode_str(slug)
+        .decode_utf8()
+        .map_err(|_| LinkedInUrlError::NotCompanyUrl)?
+        .to_lowercase();
+    if slug.chars().count() > MAX_SLUG_CHARS || !slug.chars().all(is_slug_char) {
+        return Err(LinkedInUrlError::NotCompanyUrl);
+    }
+
+    Ok(CompanyPath {
+        canonical: format!("{CANONICAL_PREFIX}{slug}"),
+        slug,
+    })
+}
+
+fn is_slug_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '-' | '_' | '.' | '&')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(url: &str) -> Result<CompanyPath, LinkedInUrlError> {
+        parse_company_path(&Url::parse(url).unwrap())
+    }
+
+    #[test]
+    fn test_company_paths() {
+        let company = parse("https://www.linkedin.com/company/Microsoft/").unwrap();
+        assert_eq!(
+            company.canonical,
+            "https://www.linkedin.com/company/microsoft"
+        );
+        assert_eq!(company.slug, "microsoft");
+
+        let company = parse("https://linkedin.com/company/1035/about/?trk=x").unwrap();
+        assert_eq!(company.canonical, "https://www.linkedin.com/company/1035");
+
+        let company =
+            parse("https://www.linkedin.com/company/soci%C3%A9t%C3%A9-g%C3%A9n%C3%A9rale").unwrap();
+        assert_eq!(company.slug, "société-générale");
+    }
+
+    #[test]
+    fn test_rejects_non_company_paths() {
+        for url in [
+            "https://www.linkedin.com/in/johndoe",
+            "https://www.linkedin.com/company/",
+            "https://www.linkedin.com/company/micro%20soft",
+            "https://www.linkedin.com/school/stanford-university",
+        ] {
+            assert!(
+                matches!(parse(url), Err(LinkedInUrlError::NotCompanyUrl)),
+                "accepted {url}"
+            );
+        }
+    }
+}
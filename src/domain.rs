@@ -0,0 +1,173 @@
+//! Recognition of official `LinkedIn` hosts.
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Registrable domain shared by every `LinkedIn` host.
+const LINKEDIN_DOMAIN: &str = "linkedin.com";
+
+/// Which `LinkedIn` host a URL pointed at.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum HostVariant {
+    /// `linkedin.com`
+    Apex,
+    /// `www.linkedin.com`
+    Www,
+    /// `m.linkedin.com`
+    Mobile,
+    /// A country or language host such as `uk.linkedin.com`, holding the two-letter code
+    Country(String),
+}
+
+/// Decides which hosts are accepted as `LinkedIn`.
+///
+/// The default policy accepts `linkedin.com`, `www.linkedin.com`, the mobile host
+/// `m.linkedin.com` and every two-letter country/language host such as
+/// `uk.linkedin.com`. Host names are compared case-insensitively. Lookalikes such as
+/// `linkedin.com.evil.io` or `evil-linkedin.com` are never accepted.
+///
+/// # Example
+///
+/// ```
+/// use credify::{DomainPolicy, HostVariant};
+///
+/// let policy = DomainPolicy::default();
+/// assert_eq!(policy.host_variant("UK.LinkedIn.com"), Some(HostVariant::Country("uk".to_string())));
+/// assert_eq!(policy.host_variant("linkedin.com.evil.io"), None);
+///
+/// let strict = DomainPolicy::strict();
+/// assert_eq!(strict.host_variant("m.linkedin.com"), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DomainPolicy {
+    allow_country_hosts: bool,
+    allow_mobile_host: bool,
+}
+
+impl Default for DomainPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DomainPolicy {
+    /// Creates the default policy, which accepts every official `LinkedIn` host.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            allow_country_hosts: true,
+            allow_mobile_host: true,
+        }
+    }
+
+    /// Creates a policy that only accepts `linkedin.com` and `www.linkedin.com`.
+    #[must_use]
+    pub const fn strict() -> Self {
+        Self {
+            allow_country_hosts: false,
+            allow_mobile_host: false,
+        }
+    }
+
+    /// Sets whether country and language hosts such as `fr.linkedin.com` are accepted.
+    #[must_use]
+    pub const fn allow_country_hosts(mut self, allow: bool) -> Self {
+        self.allow_country_hosts = allow;
+        self
+    }
+
+    /// Sets whether the mobile host `m.linkedin.com` is accepted.
+    #[must_use]
+    pub const fn allow_mobile_host(mut self, allow: bool) -> Self {
+        self.allow_mobile_host = allow;
+        self
+    }
+
+    /// Returns the variant of `host`, or `None` if the policy does not accept it.
+    #[must_use]
+    pub fn host_variant(&self, host: &str) -> Option<HostVariant> {
+        let host = host.trim_end_matches('.').to_ascii_lowercase();
+        if host == LINKEDIN_DOMAIN {
+            return Some(HostVariant::Apex);
+        }
+
+        let subdomain = host.strip_suffix(LINKEDIN_DOMAIN)?.strip_suffix('.')?;
+        match subdomain {
+            "www" => Some(HostVariant::Www),
+            "m" if self.allow_mobile_host => Some(HostVariant::Mobile),
+            code if self.allow_country_hosts
+                && code.len() == 2
+                && code.bytes().all(|b| b.is_ascii_lowercase()) =>
+            {
+                Some(HostVariant::Country(code.to_string()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the variant of the host of `url`, or `None` if it is not accepted.
+    pub(crate) fn url_host_variant(&self, url: &Url) -> Option<HostVariant> {
+        url.domain().and_then(|domain| self.host_variant(domain))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_official_hosts() {
+        let policy = DomainPolicy::default();
+        assert_eq!(policy.host_variant("linkedin.com"), Some(HostVariant::Apex));
+        assert_eq!(
+            policy.host_variant("WWW.LINKEDIN.COM"),
+            Some(HostVariant::Www)
+        );
+        assert_eq!(
+            policy.host_variant("www.linkedin.com."),
+            Some(HostVariant::Www)
+        );
+        assert_eq!(
+            policy.host_variant("m.linkedin.com"),
+            Some(HostVariant::Mobile)
+        );
+        assert_eq!(
+            policy.host_variant("fr.linkedin.com"),
+            Some(HostVariant::Country("fr".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_lookalike_hosts() {
+        let policy = DomainPolicy::default();
+        for host in [
+            "linkedin.com.evil.io",
+            "evil-linkedin.com",
+            "evillinkedin.com",
+            "www.linkedin.co",
+            "uk.www.linkedin.com",
+            "abc.linkedin.com",
+            "1a.linkedin.com",
+            ".linkedin.com",
+        ] {
+            assert_eq!(policy.host_variant(host), None, "accepted {host}");
+        }
+    }
+
+    #[test]
+    fn test_policy_toggles() {
+        let policy = DomainPolicy::new().allow_country_hosts(false);
+        assert_eq!(policy.host_variant("uk.linkedin.com"), None);
+        assert_eq!(
+            policy.host_variant("m.linkedin.com"),
+            Some(HostVariant::Mobile)
+        );
+
+        let strict = DomainPolicy::strict();
+        assert_eq!(strict.host_variant("m.linkedin.com"), None);
+        assert_eq!(
+            strict.host_variant("www.linkedin.com"),
+            Some(HostVariant::Www)
+        );
+    }
+}
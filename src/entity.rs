@@ -0,0 +1,301 @@
+//! Classification of `LinkedIn` URLs by the kind of page they point at.
+
+use crate::DomainPolicy;
+use crate::company_url::parse_company_path;
+use crate::profile_url::{ProfileTarget, parse_profile_target};
+use percent_encoding::percent_decode_str;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use url::Url;
+
+/// The kind of `LinkedIn` page a URL points at.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum EntityKind {
+    /// A personal profile, `linkedin.com/in/<username>`
+    #[default]
+    Person,
+    /// A company page, `linkedin.com/company/<slug>`
+    Company,
+    /// A showcase page, `linkedin.com/showcase/<slug>`
+    Showcase,
+    /// A school page, `linkedin.com/school/<slug>`
+    School,
+    /// A job posting, `linkedin.com/jobs/view/<id>`
+    Job,
+    /// A post, `linkedin.com/posts/<slug>` or `linkedin.com/feed/update/urn:li:activity:<id>`
+    Post,
+    /// A Pulse article, `linkedin.com/pulse/<slug>`
+    Article,
+    /// A group, `linkedin.com/groups/<id>`
+    Group,
+    /// An event, `linkedin.com/events/<slug>`
+    Event,
+    /// A newsletter, `linkedin.com/newsletters/<slug>`
+    Newsletter,
+    /// A `LinkedIn` Learning course, `linkedin.com/learning/<slug>`
+    Course,
+}
+
+impl fmt::Display for EntityKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Person => "profile",
+            Self::Company => "company page",
+            Self::Showcase => "showcase page",
+            Self::School => "school page",
+            Self::Job => "job posting",
+            Self::Post => "post",
+            Self::Article => "article",
+            Self::Group => "group",
+            Self::Event => "event",
+            Self::Newsletter => "newsletter",
+            Self::Course => "course",
+        })
+    }
+}
+
+/// What a URL points at, with the identifier of the page.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LinkedInEntity {
+    /// A recognised `LinkedIn` page and its identifier
+    Page {
+        /// What kind of page it is
+        kind: EntityKind,
+        /// The username, slug or numeric id identifying the page
+        id: String,
+    },
+    /// A `LinkedIn` URL of a kind that is not recognised, such as the feed or search
+    Unknown,
+    /// Not a URL on an official `LinkedIn` host
+    NotLinkedIn,
+}
+
+impl LinkedInEntity {
+    /// Returns the kind of page, if it was recognised.
+    #[must_use]
+    pub const fn kind(&self) -> Option<EntityKind> {
+        match self {
+            Self::Page { kind, .. } => Some(*kind),
+            Self::Unknown | Self::NotLinkedIn => None,
+        }
+    }
+
+    /// Returns the identifier of the page, if it was recognised.
+    #[must_use]
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            Self::Page { id, .. } => Some(id),
+            Self::Unknown | Self::NotLinkedIn => None,
+        }
+    }
+
+    fn page(kind: EntityKind, id: impl Into<String>) -> Self {
+        Self::Page {
+            kind,
+            id: id.into(),
+        }
+    }
+}
+
+/// Tells what kind of `LinkedIn` page a URL points at, without making network calls.
+///
+/// Profiles (including legacy `/pub/` URLs), company, showcase and school pages, job
+/// postings, posts, Pulse articles, groups, events, newsletters and Learning courses are
+/// recognised. Job and post ids are reduced to their numeric id where the URL carries one.
+///
+/// # Example
+///
+/// ```
+/// use credify::{EntityKind, LinkedInEntity, classify_linkedin_url};
+///
+/// let entity = classify_linkedin_url("https://www.linkedin.com/company/microsoft/about/");
+/// assert_eq!(entity.kind(), Some(EntityKind::Company));
+/// assert_eq!(entity.id(), Some("microsoft"));
+///
+/// let entity = classify_linkedin_url(
+///     "https://www.linkedin.com/jobs/view/senior-engineer-at-acme-3901234567/",
+/// );
+/// assert_eq!(entity.kind(), Some(EntityKind::Job));
+/// assert_eq!(entity.id(), Some("3901234567"));
+///
+/// assert_eq!(classify_linkedin_url("https://www.linkedin.com/feed/"), LinkedInEntity::Unknown);
+/// assert_eq!(classify_linkedin_url("https://example.com/in/jane"), LinkedInEntity::NotLinkedIn);
+/// ```
+#[must_use]
+pub fn classify_linkedin_url(url: &str) -> LinkedInEntity {
+    let Ok(url) = Url::parse(url) else {
+        return LinkedInEntity::NotLinkedIn;
+    };
+    if DomainPolicy::new().url_host_variant(&url).is_none() {
+        return LinkedInEntity::NotLinkedIn;
+    }
+
+    match parse_profile_target(&url) {
+        Ok(ProfileTarget::Modern(profile)) => {
+            return LinkedInEntity::page(EntityKind::Person, profile.canonical.username());
+        }
+        Ok(ProfileTarget::Legacy(legacy)) => {
+            return LinkedInEntity::page(EntityKind::Person, legacy.identifier);
+        }
+        Err(_) => {}
+    }
+    if let Ok(company) = parse_company_path(&url) {
+        return LinkedInEntity::page(EntityKind::Company, company.slug);
+    }
+
+    let segments: Vec<&str> = url
+        .path_segments()
+        .map(|segments| segments.filter(|segment| !segment.is_empty()).collect())
+        .unwrap_or_default();
+    let (kind, id) = match segments.as_slice() {
+        ["showcase", slug, ..] => (EntityKind::Showcase, decode(slug).to_lowercase()),
+        ["school", slug, ..] => (EntityKind::School, decode(slug).to_lowercase()),
+        ["jobs", "view", slug, ..] => (EntityKind::Job, trailing_number(slug).to_string()),
+        ["posts", slug, ..] => (EntityKind::Post, activity_id(slug).to_string()),
+        ["feed", "update", urn, ..] => {
+            let urn = decode(urn);
+            match urn.rsplit_once(':') {
+                Some((prefix, id)) if prefix.starts_with("urn:li:") && !id.is_empty() => {
+                    (EntityKind::Post, id.to_string())
+                }
+                _ => return LinkedInEntity::Unknown,
+            }
+        }
+        ["pulse", slug, ..] => (EntityKind::Article, decode(slug)),
+        ["groups", id, ..] => (EntityKind::Group, decode(id)),
+        ["events", slug, ..] => (EntityKind::Event, decode(slug)),
+        ["newsletters", slug, ..] => (EntityKind::Newsletter, decode(slug)),
+        ["learning", slug, ..] if !matches!(*slug, "paths" | "search" | "topics") => {
+            (EntityKind::Course, decode(slug))
+        }
+        _ => return LinkedInEntity::Unknown,
+    };
+    LinkedInEntity::page(kind, id)
+}
+
+fn decode(segment: &str) -> String {
+    percent_decode_str(segment).decode_utf8_lossy().into_owned()
+}
+
+/// Returns the numeric id at the end of a slug like `engineer-at-acme-3901234567`.
+fn trailing_number(slug: &str) -> &str {
+    match slug.rsplit_once('-') {
+        Some((_, id)) if !id.is_empty() && id.bytes().all(|b| b.is_ascii_digit()) => id,
+        _ => slug,
+    }
+}
+
+/// Returns the activity id inside a post slug like `jane_title-activity-7123-AbCd`.
+fn activity_id(slug: &str) -> &str {
+    slug.split_once("-activity-")
+        .and_then(|(_, rest)| rest.split('-').next())
+        .filter(|id| !id.is_empty() && id.bytes().all(|b| b.is_ascii_digit()))
+        .unwrap_or(slug)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn classified(url: &str) -> (Option<EntityKind>, Option<String>) {
+        let entity = classify_linkedin_url(url);
+        (entity.kind(), entity.id().map(str::to_string))
+    }
+
+    #[test]
+    fn test_recognised_pages() {
+        for (url, kind, id) in [
+            (
+                "https://www.linkedin.com/in/Jane-Doe/",
+                EntityKind::Person,
+                "jane-doe",
+            ),
+            (
+                "https://www.linkedin.com/pub/jane-doe/12/345/678",
+                EntityKind::Person,
+                "jane-doe",
+            ),
+            (
+                "https://linkedin.com/company/Microsoft",
+                EntityKind::Company,
+                "microsoft",
+            ),
+            (
+                "https://www.linkedin.com/showcase/azure/",
+                EntityKind::Showcase,
+                "azure",
+            ),
+            (
+                "https://www.linkedin.com/school/stanford-university/",
+                EntityKind::School,
+                "stanford-university",
+            ),
+            (
+                "https://www.linkedin.com/jobs/view/3901234567/",
+                EntityKind::Job,
+                "3901234567",
+            ),
+            (
+                "https://www.linkedin.com/posts/jane-doe_hiring-activity-7123456789012345678-AbCd",
+                EntityKind::Post,
+                "7123456789012345678",
+            ),
+            (
+                "https://www.linkedin.com/feed/update/urn:li:activity:7123456789012345678/",
+                EntityKind::Post,
+                "7123456789012345678",
+            ),
+            (
+                "https://www.linkedin.com/pulse/future-of-work-jane-doe",
+                EntityKind::Article,
+                "future-of-work-jane-doe",
+            ),
+            (
+                "https://www.linkedin.com/groups/12345/",
+                EntityKind::Group,
+                "12345",
+            ),
+            (
+                "https://www.linkedin.com/events/rustconf7123456/",
+                EntityKind::Event,
+                "rustconf7123456",
+            ),
+            (
+                "https://www.linkedin.com/newsletters/weekly-rust-7012345/",
+                EntityKind::Newsletter,
+                "weekly-rust-7012345",
+            ),
+            (
+                "https://www.linkedin.com/learning/learning-rust",
+                EntityKind::Course,
+                "learning-rust",
+            ),
+        ] {
+            assert_eq!(classified(url), (Some(kind), Some(id.to_string())), "{url}");
+        }
+    }
+
+    #[test]
+    fn test_unrecognised_urls() {
+        assert_eq!(
+            classify_linkedin_url("https://www.linkedin.com/"),
+            LinkedInEntity::Unknown
+        );
+        assert_eq!(
+            classify_linkedin_url("https://www.linkedin.com/feed/"),
+            LinkedInEntity::Unknown
+        );
+        assert_eq!(
+            classify_linkedin_url("https://www.linkedin.com/learning/search?keywords=rust"),
+            LinkedInEntity::Unknown
+        );
+        assert_eq!(
+            classify_linkedin_url("not a url"),
+            LinkedInEntity::NotLinkedIn
+        );
+        assert_eq!(
+            classify_linkedin_url("https://linkedin.com.evil.io/in/jane-doe"),
+            LinkedInEntity::NotLinkedIn
+        );
+    }
+}
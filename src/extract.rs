@@ -0,0 +1,258 @@
+//! Extraction of `LinkedIn` URLs from free text, HTML and Markdown.
+
+use crate::DomainPolicy;
+use crate::company_url::parse_company_path;
+use crate::entity::{LinkedInEntity, classify_linkedin_url};
+use crate::profile_url::{ProfileTarget, parse_profile_target};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+use url::Url;
+
+/// Bare, schemeless and protocol-relative mentions of any `linkedin.com` host.
+static LINKEDIN_MENTION: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i)(?:(?:https?:)?//)?(?:[a-z0-9-]+\.)*linkedin\.com/[^\s"'<>()\[\]{}`|\\^]*"#)
+        .expect("valid regex")
+});
+
+/// Google search result redirects, absolute or relative, such as `/url?q=<target>&sa=U`.
+static GOOGLE_REDIRECT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?i)(?:https?://(?:www\.)?google\.[a-z.]+)?/url\?[^\s"'<>()\[\]{}`|\\^]*"#)
+        .expect("valid regex")
+});
+
+/// Characters that end a sentence or markup rather than a URL.
+const TRAILING_PUNCTUATION: &[char] = &['.', ',', ';', ':', '!', '?', '*'];
+
+/// A `LinkedIn` URL found in a piece of text.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FoundUrl {
+    /// The first mention as a full URL, with a scheme added and redirect wrappers removed
+    pub url: String,
+    /// Canonical form of the URL, shared by every mention of the same page
+    pub canonical_url: String,
+    /// What the URL points at
+    pub entity: LinkedInEntity,
+    /// Byte ranges of every mention in the text, in order of appearance
+    pub spans: Vec<Range<usize>>,
+}
+
+/// Finds every `LinkedIn` URL mentioned in free text, HTML or Markdown.
+///
+/// Recognises full and schemeless mentions such as `www.linkedin.com/in/jane-doe,`,
+/// anchor `href`s, Markdown links and Google redirect wrappers (`/url?q=...`). Trailing
+/// punctuation is stripped, lookalike hosts are ignored, and mentions of the same page are
+/// merged by canonical form, keeping the order of their first appearance. No network
+/// request is made.
+///
+/// # Example
+///
+/// ```
+/// use credify::{EntityKind, extract_linkedin_urls};
+///
+/// let text = "Reach Jane at linkedin.com/in/Jane-Doe, or see \
+///             [her profile](https://www.linkedin.com/in/jane-doe/) and <a \
+///             href=\"https://www.linkedin.com/company/acme/\">Acme</a>.";
+/// let found = extract_linkedin_urls(text);
+///
+/// assert_eq!(found.len(), 2);
+/// assert_eq!(found[0].canonical_url, "https://www.linkedin.com/in/jane-doe");
+/// assert_eq!(found[0].spans.len(), 2);
+/// assert_eq!(&text[found[0].spans[0].clone()], "linkedin.com/in/Jane-Doe");
+/// assert_eq!(found[1].entity.kind(), Some(EntityKind::Company));
+/// ```
+#[must_use]
+pub fn extract_linkedin_urls(text: &str) -> Vec<FoundUrl> {
+    let mut mentions: Vec<(Range<usize>, Url)> = Vec::new();
+
+    for found in GOOGLE_REDIRECT.find_iter(text) {
+        let span = trim_span(text, found.range());
+        if let Some(target) = unwrap_google_redirect(&text[span.clone()]) {
+            mentions.push((span, target));
+        }
+    }
+
+    let wrapped: Vec<Range<usize>> = mentions.iter().map(|(span, _)| span.clone()).collect();
+    for found in LINKEDIN_MENTION.find_iter(text) {
+        let span = trim_span(text, found.range());
+        let inside_wrapper = wrapped
+            .iter()
+            .any(|outer| outer.start <= span.start && span.end <= outer.end);
+        if inside_wrapper || !starts_at_boundary(text, span.start) {
+            continue;
+        }
+        if let Some(url) = parse_mention(&text[span.clone()]) {
+            mentions.push((span, url));
+        }
+    }
+    mentions.sort_by_key(|(span, _)| span.start);
+
+    let mut found: Vec<FoundUrl> = Vec::new();
+    for (span, url) in mentions {
+        let canonical_url = canonical_form(&url);
+        match found
+            .iter_mut()
+            .find(|existing| existing.canonical_url == canonical_url)
+        {
+            Some(existing) => existing.spans.push(span),
+            None => found.push(FoundUrl {
+                entity: classify_linkedin_url(url.as_str()),
+                url: url.into(),
+                canonical_url,
+                spans: vec![span],
+            }),
+        }
+    }
+    found
+}
+
+/// Shrinks a match so it does not end in punctuation.
+fn trim_span(text: &str, span: Range<usize>) -> Range<usize> {
+    let trimmed = text[span.clone()].trim_end_matches(TRAILING_PUNCTUATION);
+    span.start..span.start + trimmed.len()
+}
+
+/// Rejects matches that start inside a longer word, such as `evil-linkedin.com`.
+fn starts_at_boundary(text: &str, start: usize) -> bool {
+    text[..start]
+        .chars()
+        .next_back()
+        .is_none_or(|c| !(c.is_alphanumeric() || matches!(c, '-' | '.' | '_' | '@' | '/' | '%')))
+}
+
+/// Turns a mention into a full URL on an official `LinkedIn` host.
+fn parse_mention(mention: &str) -> Option<Url> {
+    let mention = mention
+        .replace("&amp;", "&")
+        .trim_end_matches(TRAILING_PUNCTUATION)
+        .to_string();
+    let lowercase = mention.to_ascii_lowercase();
+    let full = if lowercase.starts_with("http://") || lowercase.starts_with("https://") {
+        mention
+    } else if mention.starts_with("//") {
+        format!("https:{mention}")
+    } else {
+        format!("https://{mention}")
+    };
+
+    let url = Url::parse(&full).ok()?;
+    let on_linkedin = DomainPolicy::new().url_host_variant(&url).is_some();
+    (on_linkedin && url.path() != "/").then_some(url)
+}
+
+/// Returns the `LinkedIn` URL a Google redirect points at.
+fn unwrap_google_redirect(wrapper: &str) -> Option<Url> {
+    let wrapper = wrapper.replace("&amp;", "&");
+    let wrapper = if wrapper.starts_with('/') {
+        format!("https://www.google.com{wrapper}")
+    } else {
+        wrapper
+    };
+    let url = Url::parse(&wrapper).ok()?;
+    url.query_pairs()
+        .find(|(key, _)| key == "q" || key == "url")
+        .and_then(|(_, target)| parse_mention(&target))
+}
+
+/// Reduces a URL to the page it points at, so mentions of one page compare equal.
+fn canonical_form(url: &Url) -> String {
+    match parse_profile_target(url) {
+        Ok(ProfileTarget::Modern(profile)) => return profile.canonical.into_string(),
+        Ok(ProfileTarget::Legacy(legacy)) => return legacy.url,
+        Err(_) => {}
+    }
+    if let Ok(company) = parse_company_path(url) {
+        return company.canonical;
+    }
+    format!(
+        "https://www.linkedin.com{}",
+        url.path().trim_end_matches('/')
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EntityKind;
+
+    fn urls(text: &str) -> Vec<String> {
+        extract_linkedin_urls(text)
+            .into_iter()
+            .map(|found| found.url)
+            .collect()
+    }
+
+    #[test]
+    fn test_mentions_in_text_html_and_markdown() {
+        let text = concat!(
+            "Profiles: www.linkedin.com/in/foo, https://uk.linkedin.com/in/bar-baz/?trk=x. ",
+            "See [Jane](https://www.linkedin.com/in/jane-doe) or ",
+            "<a href=\"//linkedin.com/company/acme/about/\">Acme</a>; ",
+            "**linkedin.com/jobs/view/3901234567**!"
+        );
+        assert_eq!(
+            urls(text),
+            [
+                "https://www.linkedin.com/in/foo",
+                "https://uk.linkedin.com/in/bar-baz/?trk=x",
+                "https://www.linkedin.com/in/jane-doe",
+                "https://linkedin.com/company/acme/about/",
+                "https://linkedin.com/jobs/view/3901234567",
+            ]
+        );
+
+        let found = extract_linkedin_urls(text);
+        assert_eq!(&text[found[0].spans[0].clone()], "www.linkedin.com/in/foo");
+        assert_eq!(
+            found[3].canonical_url,
+            "https://www.linkedin.com/company/acme"
+        );
+        assert_eq!(found[4].entity.kind(), Some(EntityKind::Job));
+    }
+
+    #[test]
+    fn test_google_redirects_are_unwrapped() {
+        let text = concat!(
+            "<a href=\"/url?q=https://www.linkedin.com/in/jane-doe&amp;sa=U&amp;ved=2ah\">",
+            "Jane Doe - LinkedIn</a> ",
+            "https://www.google.com/url?url=https%3A%2F%2Flinkedin.com%2Fin%2Fjohn-smith&sa=t"
+        );
+        let found = extract_linkedin_urls(text);
+
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].url, "https://www.linkedin.com/in/jane-doe");
+        assert!(text[found[0].spans[0].clone()].starts_with("/url?q="));
+        assert_eq!(found[0].spans.len(), 1);
+        assert_eq!(
+            found[1].canonical_url,
+            "https://www.linkedin.com/in/john-smith"
+        );
+    }
+
+    #[test]
+    fn test_duplicates_and_lookalikes() {
+        let text = "linkedin.com/in/Jane-Doe and https://de.linkedin.com/in/jane-doe/en and \
+                    evil-linkedin.com/in/x, linkedin.com.evil.io/in/y, user@linkedin.com/z, \
+                    https://www.linkedin.com/";
+        let found = extract_linkedin_urls(text);
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(
+            found[0].canonical_url,
+            "https://www.linkedin.com/in/jane-doe"
+        );
+        assert_eq!(
+            found[0]
+                .spans
+                .iter()
+                .map(|span| &text[span.clone()])
+                .collect::<Vec<_>>(),
+            [
+                "linkedin.com/in/Jane-Doe",
+                "https://de.linkedin.com/in/jane-doe/en"
+            ]
+        );
+        assert!(extract_linkedin_urls("no links here").is_empty());
+    }
+}
@@ -0,0 +1,499 @@
+//! Validation cache in a JSON-lines file shared by several processes.
+
+use crate::{AIValidationResult, ValidationCache};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Name of the cache file inside the cache directory.
+const CACHE_FILE: &str = "validations.jsonl";
+
+/// Name of the lock file taken while the cache file is written.
+const LOCK_FILE: &str = "validations.lock";
+
+/// How long to wait for another process to release the lock.
+const LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Pause between two attempts to take the lock.
+const LOCK_RETRY: Duration = Duration::from_millis(5);
+
+/// Age after which a lock file is considered left behind by a crashed process.
+///
+/// Shorter than [`LOCK_TIMEOUT`], so a crashed writer does not make other processes give
+/// up. Writers hold the lock for one append or one compaction, and a compaction refreshes
+/// it before replacing the file.
+const STALE_LOCK: Duration = Duration::from_secs(2);
+
+/// Fewest index entries at which results that expired after being read are swept out.
+const MIN_SWEEP: usize = 1024;
+
+/// A [`ValidationCache`] kept in an append-only JSON-lines file.
+///
+/// Every process opening the same directory shares the cached results. Writers take a lock
+/// file and append one line per result; readers never wait, and only look at the lines
+/// added since their last read. Expired results are skipped when read and dropped from the
+/// file by [`compact`](Self::compact), which also drops results that were replaced.
+///
+/// Like every cache, it is best effort: a result that cannot be written or read is
+/// validated again.
+///
+/// # Example
+///
+/// ```no_run
+/// use credify::{CachePolicy, FileCache, LinkedInValidator};
+///
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let store = FileCache::open("/var/cache/credify")?;
+/// store.compact()?;
+///
+/// let validator = LinkedInValidator::builder()
+///     .cache_store(CachePolicy::new(), store)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct FileCache {
+    dir: PathBuf,
+    index: Mutex<Index>,
+}
+
+/// What this process has read of the cache file.
+#[derive(Debug, Default)]
+struct Index {
+    /// Generation of the file that was read; a compaction starts a new one
+    generation: String,
+    /// Byte offset of the first line not read yet
+    offset: u64,
+    entries: HashMap<String, Record>,
+    /// Number of entries at which expired ones are swept out next
+    sweep_at: usize,
+}
+
+/// First line of the cache file.
+#[derive(Debug, Serialize, Deserialize)]
+struct Header {
+    generation: String,
+}
+
+/// One cached result.
+#[derive(Debug, Serialize, Deserialize)]
+struct Record {
+    key: String,
+    /// Expiry time in milliseconds since the Unix epoch
+    expires_at: u64,
+    result: AIValidationResult,
+}
+
+impl Record {
+    fn is_expired(&self, now: u64) -> bool {
+        self.expires_at <= now
+    }
+}
+
+impl FileCache {
+    /// Opens the cache in `dir`, creating the directory and the cache file if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or the cache file cannot be created, or if
+    /// another process holds the lock for longer than 5 seconds.
+    pub fn open(dir: impl AsRef<Path>) -> io::Result<Self> {
+        let cache = Self {
+            dir: dir.as_ref().to_path_buf(),
+            index: Mutex::new(Index::default()),
+        };
+        fs::create_dir_all(&cache.dir)?;
+        let lock = cache.lock_file()?;
+        if !cache.path().exists() {
+            cache.rewrite(&lock, &[])?;
+        }
+        Ok(cache)
+    }
+
+    /// Returns the path of the cache file.
+    #[must_use]
+    pub fn path(&self) -> PathBuf {
+        self.dir.join(CACHE_FILE)
+    }
+
+    /// Rewrites the cache file without expired and replaced results.
+    ///
+    /// Returns how many results were kept. Other processes pick up the compacted file on
+    /// their next read.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache file cannot be read or replaced, if another process
+    /// holds the lock for longer than 5 seconds, or if the compaction took so long that
+    /// another process broke its lock as stale.
+    pub fn compact(&self) -> io::Result<usize> {
+        let lock = self.lock_file()?;
+        let mut index = Index::default();
+        self.read_new_lines(&mut index)?;
+        lock.refresh()?;
+
+        let now = now_millis();
+        let mut records: Vec<Record> = index
+            .entries
+            .into_values()
+            .filter(|record| !record.is_expired(now))
+            .collect();
+        records.sort_by(|a, b| a.key.cmp(&b.key));
+        self.rewrite(&lock, &records)?;
+        Ok(records.len())
+    }
+
+    /// Reads the lines appended since the last read, starting over after a compaction.
+    ///
+    /// A line still being written is left for the next read. Expired results are not kept,
+    /// and those that expired since they were read are swept out whenever the index has
+    /// doubled, so it only grows with the results still valid.
+    fn read_new_lines(&self, index: &mut Index) -> io::Result<()> {
+        let mut reader = BufReader::new(File::open(self.path())?);
+        let mut line = String::new();
+        let header_len = reader.read_line(&mut line)?;
+        let header: Header = serde_json::from_str(&line)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        if header.generation != index.generation {
+            *index = Index {
+                generation: header.generation,
+                offset: header_len as u64,
+                ..Index::default()
+            };
+        }
+
+        let now = now_millis();
+        reader.seek(SeekFrom::Start(index.offset))?;
+        loop {
+            line.clear();
+            let len = reader.read_line(&mut line)?;
+            if len == 0 || !line.ends_with('\n') {
+                break;
+            }
+            index.offset += len as u64;
+            if let Ok(record) = serde_json::from_str::<Record>(&line) {
+                if record.is_expired(now) {
+                    index.entries.remove(&record.key);
+                } else {
+                    index.entries.insert(record.key.clone(), record);
+                }
+            }
+        }
+
+        if index.entries.len() >= index.sweep_at {
+            index.entries.retain(|_, record| !record.is_expired(now));
+            index.sweep_at = (index.entries.len() * 2).max(MIN_SWEEP);
+        }
+        Ok(())
+    }
+
+    /// Replaces the cache file with a new generation holding `records`.
+    ///
+    /// The file is only replaced if `lock` is still held, since results appended by a
+    /// process that broke it would be lost.
+    fn rewrite(&self, lock: &LockFile, records: &[Record]) -> io::Result<()> {
+        let temp = self
+            .dir
+            .join(format!("{CACHE_FILE}.{}.tmp", std::process::id()));
+        let mut contents = to_line(&Header {
+            generation: new_generation(),
+        })?;
+        for record in records {
+            contents.push_str(&to_line(record)?);
+        }
+        let mut file = File::create(&temp)?;
+        file.write_all(contents.as_bytes())?;
+        file.sync_all()?;
+        if let Err(e) = lock.refresh() {
+            let _ = fs::remove_file(&temp);
+            return Err(e);
+        }
+        fs::rename(&temp, self.path())
+    }
+
+    /// Appends `record` to the cache file.
+    fn append(&self, record: &Record) -> io::Result<()> {
+        let line = to_line(record)?;
+        let _lock = self.lock_file()?;
+        OpenOptions::new()
+            .append(true)
+            .open(self.path())?
+            .write_all(line.as_bytes())
+    }
+
+    /// Takes the lock file, waiting for other processes to release it.
+    ///
+    /// The lock file holds a token naming its holder, so a holder whose lock was broken as
+    /// stale does not remove the lock of the next one.
+    fn lock_file(&self) -> io::Result<LockFile> {
+        let path = self.dir.join(LOCK_FILE);
+        let token = new_generation();
+        let started = Instant::now();
+        loop {
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(mut file) => {
+                    let lock = LockFile { path, token };
+                    file.write_all(lock.token.as_bytes())?;
+                    return Ok(lock);
+                }
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+                    if break_stale_lock(&path) {
+                        continue;
+                    }
+                    if started.elapsed() >= LOCK_TIMEOUT {
+                        return Err(io::Error::new(
+                            io::ErrorKind::TimedOut,
+                            format!("cache lock {} is held", path.display()),
+                        ));
+                    }
+                    thread::sleep(LOCK_RETRY);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn lock_index(&self) -> std::sync::MutexGuard<'_, Index> {
+        self.index.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl ValidationCache for FileCache {
+    fn get(&self, key: &str) -> Option<AIValidationResult> {
+        let mut index = self.lock_index();
+        self.read_new_lines(&mut index).ok()?;
+        let record = index.entries.get(key)?;
+        if record.is_expired(now_millis()) {
+            index.entries.remove(key);
+            return None;
+        }
+        Some(record.result.clone())
+    }
+
+    fn insert(&self, key: &str, result: &AIValidationResult, ttl: Duration) {
+        let ttl = u64::try_from(ttl.as_millis()).unwrap_or(u64::MAX);
+        let record = Record {
+            key: key.to_string(),
+            expires_at: now_millis().saturating_add(ttl),
+            result: result.clone(),
+        };
+        // The cache is best effort: the result is validated again next time
+        let _ = self.append(&record);
+    }
+
+    fn clear(&self) {
+        if let Ok(lock) = self.lock_file() {
+            let _ = self.rewrite(&lock, &[]);
+        }
+    }
+}
+
+/// Removes the lock file when dropped, unless another process broke it as stale.
+struct LockFile {
+    path: PathBuf,
+    token: String,
+}
+
+impl LockFile {
+    /// Checks that the lock is still held and restarts its [`STALE_LOCK`] age.
+    ///
+    /// The token is read through the handle whose modified time is set, so a lock taken
+    /// over by another process is never refreshed.
+    fn refresh(&self) -> io::Result<()> {
+        let mut file = OpenOptions::new().read(true).write(true).open(&self.path)?;
+        let mut token = String::new();
+        file.read_to_string(&mut token)?;
+        if token != self.token {
+            return Err(io::Error::other(format!(
+                "cache lock {} was broken as stale",
+                self.path.display()
+            )));
+        }
+        file.set_modified(SystemTime::now())
+    }
+}
+
+impl Drop for LockFile {
+    fn drop(&mut self) {
+        if fs::read_to_string(&self.path).is_ok_and(|token| token == self.token) {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
+
+/// Removes the lock at `path` if it is older than [`STALE_LOCK`], returning whether it did.
+///
+/// The lock is claimed by renaming it to a name only this call uses, so of several
+/// processes finding the same stale lock only one removes it. The claimed file is checked
+/// again, and a process that claimed a lock taken again in the meantime puts it back.
+fn break_stale_lock(path: &Path) -> bool {
+    if !is_stale(path) {
+        return false;
+    }
+    let claimed = path.with_extension(format!("lock.{}.stale", new_generation()));
+    if fs::rename(path, &claimed).is_err() {
+        return false;
+    }
+    let broke_stale = is_stale(&claimed);
+    if !broke_stale {
+        // Fails if yet another process took the lock, which then stays with it
+        let _ = fs::hard_link(&claimed, path);
+    }
+    let _ = fs::remove_file(&claimed);
+    broke_stale
+}
+
+fn is_stale(lock: &Path) -> bool {
+    fs::metadata(lock)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+        .is_some_and(|age| age > STALE_LOCK)
+}
+
+fn to_line(value: &impl Serialize) -> io::Result<String> {
+    let mut line = serde_json::to_string(value)?;
+    line.push('\n');
+    Ok(line)
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |now| u64::try_from(now.as_millis()).unwrap_or(u64::MAX))
+}
+
+/// Returns an identifier that differs between rewrites of the cache file and between
+/// lock holders.
+fn new_generation() -> String {
+    static SEQUENCE: AtomicU64 = AtomicU64::new(0);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |now| now.as_nanos());
+    let sequence = SEQUENCE.fetch_add(1, Ordering::Relaxed);
+    format!("{nanos:x}-{:x}-{sequence:x}", std::process::id())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LinkedInUrlError, report, settings::ValidationSettings};
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("credify-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn result() -> AIValidationResult {
+        report::ai_result(
+            "https://www.linkedin.com/in/johndoe",
+            &ValidationSettings::new(),
+            || Err(LinkedInUrlError::TransportError("timeout".to_string())),
+        )
+    }
+
+    #[test]
+    fn test_results_are_shared_between_instances() {
+        let dir = temp_dir("file-cache-shared");
+        let writer = FileCache::open(&dir).expect("cache opens");
+        let reader = FileCache::open(&dir).expect("cache opens");
+        assert!(reader.get("a").is_none());
+
+        writer.insert("a", &result(), Duration::from_secs(60));
+        writer.insert("b", &result(), Duration::ZERO);
+        assert!(reader.get("a").is_some());
+        assert!(reader.get("b").is_none());
+
+        reader.clear();
+        assert!(writer.get("a").is_none());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_compaction_drops_expired_and_replaced_results() {
+        let dir = temp_dir("file-cache-compact");
+        let cache = FileCache::open(&dir).expect("cache opens");
+        let other = FileCache::open(&dir).expect("cache opens");
+        cache.insert("a", &result(), Duration::from_secs(60));
+        cache.insert("a", &result(), Duration::from_secs(60));
+        cache.insert("b", &result(), Duration::ZERO);
+        assert!(other.get("a").is_some());
+
+        assert_eq!(cache.compact().expect("compaction succeeds"), 1);
+        let lines = fs::read_to_string(cache.path()).expect("cache file is readable");
+        assert_eq!(lines.lines().count(), 2);
+
+        // The other instance starts over with the compacted file
+        other.insert("c", &result(), Duration::from_secs(60));
+        assert!(other.get("a").is_some());
+        assert!(cache.get("c").is_some());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_index_drops_expired_results() {
+        let dir = temp_dir("file-cache-index");
+        let cache = FileCache::open(&dir).expect("cache opens");
+        cache.insert("a", &result(), Duration::from_secs(60));
+        cache.insert("b", &result(), Duration::ZERO);
+        cache.insert("c", &result(), Duration::from_millis(200));
+        assert!(cache.get("a").is_some());
+        assert_eq!(cache.lock_index().entries.len(), 2);
+
+        thread::sleep(Duration::from_millis(250));
+        cache.lock_index().sweep_at = 0;
+        cache.insert("a", &result(), Duration::from_secs(60));
+        assert!(cache.get("a").is_some());
+        assert_eq!(cache.lock_index().entries.len(), 1);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_stale_lock_is_broken_once() {
+        let dir = temp_dir("file-cache-stale-lock");
+        let cache = FileCache::open(&dir).expect("cache opens");
+        let lock = dir.join(LOCK_FILE);
+        let file = File::create(&lock).expect("lock is created");
+        assert!(!break_stale_lock(&lock));
+
+        let crashed_at = SystemTime::now() - STALE_LOCK * 2;
+        file.set_modified(crashed_at).expect("lock mtime is set");
+        let started = Instant::now();
+        cache.insert("a", &result(), Duration::from_secs(60));
+        assert!(started.elapsed() < LOCK_TIMEOUT);
+        assert!(cache.get("a").is_some());
+        assert!(!lock.exists());
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_slow_compaction_keeps_concurrent_inserts() {
+        let dir = temp_dir("file-cache-slow-compaction");
+        let compacting = FileCache::open(&dir).expect("cache opens");
+        let inserting = FileCache::open(&dir).expect("cache opens");
+
+        // A compaction stalls long enough for its lock to look stale while it holds it
+        let lock = compacting.lock_file().expect("lock is taken");
+        File::options()
+            .write(true)
+            .open(&lock.path)
+            .and_then(|file| file.set_modified(SystemTime::now() - STALE_LOCK * 2))
+            .expect("lock mtime is set");
+        inserting.insert("a", &result(), Duration::from_secs(60));
+
+        assert!(compacting.rewrite(&lock, &[]).is_err());
+        drop(lock);
+        assert!(compacting.get("a").is_some());
+        assert_eq!(compacting.compact().expect("compaction succeeds"), 1);
+        assert!(inserting.get("a").is_some());
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
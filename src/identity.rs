@@ -0,0 +1,511 @@
+//! Scoring whether a profile belongs to the person an agent is looking for.
+
+use crate::{AIValidationResult, PublicProfileSummary};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Score from which a profile is reported as a likely match.
+const LIKELY_THRESHOLD: f32 = 0.75;
+
+/// Score from which a profile is reported as a possible match.
+const POSSIBLE_THRESHOLD: f32 = 0.4;
+
+/// Weight of the vanity name when nothing else is known about the profile.
+const VANITY_WEIGHT: f32 = 1.0;
+
+/// Weight of the vanity name next to the display name, which says the same more reliably.
+const VANITY_WEIGHT_WITH_NAME: f32 = 0.5;
+
+const DISPLAY_NAME_WEIGHT: f32 = 2.0;
+const COMPANY_WEIGHT: f32 = 1.5;
+const TITLE_WEIGHT: f32 = 1.0;
+const LOCATION_WEIGHT: f32 = 0.5;
+
+/// Words left out when comparing company names.
+const COMPANY_SUFFIXES: &[&str] = &[
+    "inc",
+    "llc",
+    "ltd",
+    "limited",
+    "corp",
+    "corporation",
+    "co",
+    "company",
+    "gmbh",
+    "ag",
+    "sa",
+    "plc",
+    "bv",
+    "the",
+];
+
+/// What an agent knows about the person it is looking for.
+///
+/// # Example
+///
+/// ```
+/// use credify::PersonHint;
+///
+/// let hint = PersonHint::new("Jane Smith").company("Acme").title("Engineer");
+/// assert_eq!(hint.name, "Jane Smith");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersonHint {
+    /// Full name, first name first
+    pub name: String,
+    /// Current employer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub company: Option<String>,
+    /// Job title
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Where the person is based
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+impl PersonHint {
+    /// Creates a hint for the person called `name`.
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the person's current employer.
+    #[must_use]
+    pub fn company(mut self, company: impl Into<String>) -> Self {
+        self.company = Some(company.into());
+        self
+    }
+
+    /// Sets the person's job title.
+    #[must_use]
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets where the person is based.
+    #[must_use]
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.location = Some(location.into());
+        self
+    }
+}
+
+/// How likely a profile belongs to the expected person.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MatchVerdict {
+    /// The profile is very likely the expected person's
+    Likely,
+    /// Some signals agree, but not enough to be sure
+    Possible,
+    /// The profile is probably someone else's, or does not exist
+    Unlikely,
+}
+
+/// What a signal compares.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MatchSignalKind {
+    /// The profile exists at all
+    Existence,
+    /// The vanity name in the URL, such as `jsmith-42`
+    VanityName,
+    /// The name shown on the profile page
+    DisplayName,
+    /// The employer, looked up in the headline and page description
+    Company,
+    /// The job title, looked up in the headline
+    Title,
+    /// The location shown on the profile page
+    Location,
+}
+
+impl fmt::Display for MatchSignalKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Existence => "existence",
+            Self::VanityName => "vanity name",
+            Self::DisplayName => "display name",
+            Self::Company => "company",
+            Self::Title => "title",
+            Self::Location => "location",
+        })
+    }
+}
+
+/// One piece of evidence for or against a match.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatchSignal {
+    /// What was compared
+    pub kind: MatchSignalKind,
+    /// How well the profile agrees with the hint, from 0.0 to 1.0
+    pub similarity: f32,
+    /// How much the signal counts towards the score
+    pub weight: f32,
+    /// Human-readable explanation of the comparison
+    pub explanation: String,
+}
+
+/// Whether a profile belongs to the expected person, and why.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdentityMatch {
+    /// Combined score from 0.0 to 1.0: the weighted similarity of the signals times the
+    /// confidence that the profile exists
+    pub score: f32,
+    /// Verdict derived from the score
+    pub verdict: MatchVerdict,
+    /// Every signal that was compared, in order of weight
+    pub signals: Vec<MatchSignal>,
+    /// The validation the signals were read from
+    pub validation: AIValidationResult,
+}
+
+/// Scores how well a validated profile matches `expected`. No network request is made.
+///
+/// The vanity name of the URL is always compared. The display name, company, title and
+/// location are compared when the validation carries a
+/// [`profile_summary`](AIValidationResult::profile_summary); validate with
+/// [`fetch_profile_summary`](crate::LinkedInValidatorBuilder::fetch_profile_summary) to get
+/// one. Profiles that were rejected score 0.
+///
+/// # Example
+///
+/// ```
+/// use credify::{
+///     HttpResponse, LinkedInValidator, MatchVerdict, PersonHint, ScriptedTransport,
+///     score_identity,
+/// };
+///
+/// let transport = ScriptedTransport::new();
+/// transport.enqueue(
+///     HttpResponse::new(200, "https://www.linkedin.com/in/jsmith-42")
+///         .with_body("<title>Jane Smith - Engineer at Acme | LinkedIn</title>"),
+/// );
+/// let validator = LinkedInValidator::with_transport(transport).with_profile_summary(true);
+/// let result = validator.ai_validate("https://www.linkedin.com/in/jsmith-42");
+///
+/// let hint = PersonHint::new("Jane Smith").company("Acme");
+/// assert_eq!(score_identity(&hint, result).verdict, MatchVerdict::Likely);
+/// ```
+#[must_use]
+pub fn score_identity(expected: &PersonHint, validation: AIValidationResult) -> IdentityMatch {
+    if !validation.is_valid {
+        let signals = vec![MatchSignal {
+            kind: MatchSignalKind::Existence,
+            similarity: 0.0,
+            weight: 1.0,
+            explanation: format!("The profile could not be validated: {}", validation.reason),
+        }];
+        return IdentityMatch {
+            score: 0.0,
+            verdict: MatchVerdict::Unlikely,
+            signals,
+            validation,
+        };
+    }
+
+    let name = Name::parse(&expected.name);
+    let summary = validation.profile_summary.as_ref();
+    let display_name = summary.and_then(|summary| summary.display_name.as_deref());
+
+    let mut signals = Vec::new();
+    if let Some(username) = &validation.username {
+        let weight = if display_name.is_some() {
+            VANITY_WEIGHT_WITH_NAME
+        } else {
+            VANITY_WEIGHT
+        };
+        signals.push(vanity_signal(&name, username, weight));
+    }
+    if let Some(display_name) = display_name {
+        signals.push(display_name_signal(&name, display_name));
+    }
+    if let Some(summary) = summary {
+        signals.extend(details_signals(expected, summary));
+    }
+    signals.sort_by(|a, b| b.weight.total_cmp(&a.weight));
+
+    let total_weight: f32 = signals.iter().map(|signal| signal.weight).sum();
+    let similarity = if total_weight > 0.0 {
+        signals
+            .iter()
+            .map(|signal| signal.similarity * signal.weight)
+            .sum::<f32>()
+            / total_weight
+    } else {
+        0.0
+    };
+    let score = similarity * validation.confidence;
+
+    IdentityMatch {
+        score,
+        verdict: verdict(score),
+        signals,
+        validation,
+    }
+}
+
+fn verdict(score: f32) -> MatchVerdict {
+    if score >= LIKELY_THRESHOLD {
+        MatchVerdict::Likely
+    } else if score >= POSSIBLE_THRESHOLD {
+        MatchVerdict::Possible
+    } else {
+        MatchVerdict::Unlikely
+    }
+}
+
+/// A person's name split into lowercase, accent-free words.
+struct Name {
+    first: Option<String>,
+    last: Option<String>,
+    words: Vec<String>,
+}
+
+impl Name {
+    fn parse(name: &str) -> Self {
+        let words = words(name);
+        Self {
+            first: words.first().cloned(),
+            last: (words.len() > 1).then(|| words[words.len() - 1].clone()),
+            words,
+        }
+    }
+}
+
+/// Compares the vanity name, such as `jane-smith`, `jsmith-42` or `smithj`, with the name.
+fn vanity_signal(name: &Name, username: &str, weight: f32) -> MatchSignal {
+    // Drop the numeric and hexadecimal suffixes LinkedIn adds to taken names
+    let vanity: String = username
+        .split(['-', '_', '.'])
+        .map(|part| part.trim_end_matches(|c: char| c.is_ascii_digit()))
+        .filter(|part| !part.is_empty() && !is_generated_suffix(part))
+        .collect::<Vec<_>>()
+        .concat();
+    let vanity = fold(&vanity);
+
+    let has = |word: &Option<String>| {
+        word.as_ref()
+            .is_some_and(|word| word.len() > 1 && vanity.contains(word.as_str()))
+    };
+    let initial = name.first.as_ref().and_then(|first| first.chars().next());
+    let has_initial_and_last = name.last.as_ref().is_some_and(|last| {
+        initial.is_some_and(|initial| {
+            vanity == format!("{initial}{last}") || vanity == format!("{last}{initial}")
+        })
+    });
+
+    let (similarity, explanation) = if has(&name.first) && has(&name.last) {
+        (1.0, "contains the first and last name")
+    } else if has_initial_and_last {
+        (0.8, "is the first initial and the last name")
+    } else if has(&name.last) {
+        (0.5, "contains the last name only")
+    } else if has(&name.first) {
+        (0.3, "contains the first name only")
+    } else {
+        (0.0, "shares no name with the expected person")
+    };
+    MatchSignal {
+        kind: MatchSignalKind::VanityName,
+        similarity,
+        weight,
+        explanation: format!("Vanity name \"{username}\" {explanation}"),
+    }
+}
+
+/// `LinkedIn` suffixes such as `1a2b3c4d`: long alphanumeric runs containing digits.
+fn is_generated_suffix(part: &str) -> bool {
+    part.len() >= 5
+        && part.chars().all(|c| c.is_ascii_hexdigit())
+        && part.chars().any(|c| c.is_ascii_digit())
+}
+
+fn display_name_signal(name: &Name, display_name: &str) -> MatchSignal {
+    let shown = words(display_name);
+    let has = |word: &Option<String>| word.as_ref().is_some_and(|word| shown.contains(word));
+    let (similarity, explanation) =
+        if !name.words.is_empty() && name.words.iter().all(|word| shown.contains(word)) {
+            (1.0, "matches the expected name")
+        } else if has(&name.last) {
+            (0.6, "shares the last name only")
+        } else if has(&name.first) {
+            (0.3, "shares the first name only")
+        } else {
+            (0.0, "does not match the expected name")
+        };
+    MatchSignal {
+        kind: MatchSignalKind::DisplayName,
+        similarity,
+        weight: DISPLAY_NAME_WEIGHT,
+        explanation: format!("Display name \"{display_name}\" {explanation}"),
+    }
+}
+
+/// Compares the company, title and location of the hint with the page.
+fn details_signals(expected: &PersonHint, summary: &PublicProfileSummary) -> Vec<MatchSignal> {
+    let mut signals = Vec::new();
+    let work = [
+        summary.headline.as_deref(),
+        summary.og_tags.get("og:description").map(String::as_str),
+        summary
+            .json_ld
+            .as_ref()
+            .and_then(|person| person.get("worksFor"))
+            .map(|works_for| works_for.to_string())
+            .as_deref(),
+    ]
+    .into_iter()
+    .flatten()
+    .flat_map(words)
+    .collect::<Vec<_>>();
+
+    if let Some(company) = &expected.company {
+        let wanted: Vec<String> = words(company)
+            .into_iter()
+            .filter(|word| !COMPANY_SUFFIXES.contains(&word.as_str()))
+            .collect();
+        if !work.is_empty() && !wanted.is_empty() {
+            let found = wanted.iter().all(|word| work.contains(word));
+            signals.push(MatchSignal {
+                kind: MatchSignalKind::Company,
+                similarity: if found { 1.0 } else { 0.0 },
+                weight: COMPANY_WEIGHT,
+                explanation: if found {
+                    format!("Company \"{company}\" appears in the headline or description")
+                } else {
+                    format!("Company \"{company}\" does not appear in the headline or description")
+                },
+            });
+        }
+    }
+
+    if let (Some(title), Some(headline)) = (&expected.title, &summary.headline) {
+        signals.push(overlap_signal(
+            MatchSignalKind::Title,
+            TITLE_WEIGHT,
+            title,
+            headline,
+        ));
+    }
+    if let (Some(location), Some(shown)) = (&expected.location, &summary.location) {
+        signals.push(overlap_signal(
+            MatchSignalKind::Location,
+            LOCATION_WEIGHT,
+            location,
+            shown,
+        ));
+    }
+    signals
+}
+
+/// Scores the share of the words of `expected` that appear in `shown`.
+fn overlap_signal(kind: MatchSignalKind, weight: f32, expected: &str, shown: &str) -> MatchSignal {
+    let wanted = words(expected);
+    let present = words(shown);
+    let found = wanted.iter().filter(|word| present.contains(word)).count();
+    #[allow(clippy::cast_precision_loss)] // word counts are tiny
+    let similarity = if wanted.is_empty() {
+        0.0
+    } else {
+        found as f32 / wanted.len() as f32
+    };
+    MatchSignal {
+        kind,
+        similarity,
+        weight,
+        explanation: format!(
+            "{found} of {} words of the expected {kind} \"{expected}\" appear in \"{shown}\"",
+            wanted.len()
+        ),
+    }
+}
+
+/// Splits text into lowercase, accent-free words.
+pub(crate) fn words(text: &str) -> Vec<String> {
+    fold(text)
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Lowercases `text` and strips the accents of Latin letters.
+pub(crate) fn fold(text: &str) -> String {
+    let mut folded = String::with_capacity(text.len());
+    for c in text.chars().flat_map(char::to_lowercase) {
+        match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' => folded.push('a'),
+            'ç' | 'č' | 'ć' => folded.push('c'),
+            'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ę' | 'ě' => folded.push('e'),
+            'ì' | 'í' | 'î' | 'ï' | 'ī' => folded.push('i'),
+            'ñ' | 'ń' | 'ň' => folded.push('n'),
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => folded.push('o'),
+            'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' => folded.push('u'),
+            'ý' | 'ÿ' => folded.push('y'),
+            'ś' | 'š' => folded.push('s'),
+            'ź' | 'ż' | 'ž' => folded.push('z'),
+            'ł' => folded.push('l'),
+            'ř' => folded.push('r'),
+            'ß' => folded.push_str("ss"),
+            c => folded.push(c),
+        }
+    }
+    folded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vanity_name_patterns() {
+        let name = Name::parse("Jane Smith");
+        let similarity = |username| vanity_signal(&name, username, 1.0).similarity;
+
+        assert!((similarity("jane-smith-1a2b3c4d") - 1.0).abs() < f32::EPSILON);
+        assert!((similarity("jsmith-42") - 0.8).abs() < f32::EPSILON);
+        assert!((similarity("smithj") - 0.8).abs() < f32::EPSILON);
+        assert!((similarity("smith-consulting") - 0.5).abs() < f32::EPSILON);
+        assert!(similarity("johndoe") < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_names_are_folded() {
+        let name = Name::parse("José Müller");
+        let signal = display_name_signal(&name, "Jose MÜLLER, PhD");
+        assert!((signal.similarity - 1.0).abs() < f32::EPSILON);
+        assert_eq!(words("Acme, Inc."), ["acme", "inc"]);
+    }
+
+    #[test]
+    fn test_details_signals() {
+        let summary = PublicProfileSummary {
+            headline: Some("Senior Engineer at Acme".to_string()),
+            location: Some("Berlin, Germany".to_string()),
+            ..PublicProfileSummary::default()
+        };
+        let hint = PersonHint::new("Jane Smith")
+            .company("Acme Inc.")
+            .title("Software Engineer")
+            .location("Berlin");
+        let signals = details_signals(&hint, &summary);
+
+        let similarities: Vec<(MatchSignalKind, f32)> = signals
+            .iter()
+            .map(|signal| (signal.kind, signal.similarity))
+            .collect();
+        assert_eq!(
+            similarities,
+            [
+                (MatchSignalKind::Company, 1.0),
+                (MatchSignalKind::Title, 0.5),
+                (MatchSignalKind::Location, 1.0)
+            ]
+        );
+    }
+}
@@ -126,6 +126,7 @@
 //! | **AI-Optimized** | `ai_*` functions | Structured data for AI |
 //! | **LLM-Friendly** | `validate_for_llm*` | Verbose text reports |
 //! | **Traditional** | `LinkedInValidator` | Direct validation |
+//! | **Reusable Async** | `AsyncLinkedInValidator` | High-throughput async validation |
 //!
 //! # Important: Async Usage
 //!
@@ -144,17 +145,78 @@
 //! # }
 //! ```
 
-use once_cell::sync::Lazy;
-use regex::Regex;
 use serde::{Deserialize, Serialize};
-use serde_json::json;
+use std::sync::Arc;
+use std::thread;
+use std::time::Instant;
 use thiserror::Error;
 use url::Url;
 
+mod async_validator;
+mod batch;
+mod builder;
+mod cache;
+mod classifier;
+mod company_url;
+mod domain;
+mod entity;
+mod extract;
+mod file_cache;
+mod identity;
+mod outcome;
+mod profile_url;
+mod repair;
+mod report;
+mod retry;
 mod rig_helpers;
+mod rules;
+mod settings;
+mod short_link;
+mod suggest;
+mod summary;
+mod throttle;
+mod transport;
+pub use async_validator::AsyncLinkedInValidator;
+use async_validator::default_async_validator;
+pub use batch::BatchOptions;
+pub use builder::LinkedInValidatorBuilder;
+use cache::ResultCache;
+pub use cache::{CachePolicy, MemoryCache, ValidationCache};
+use classifier::{BOT_DETECTION_STATUS, BYPASS_COOKIE};
+pub use classifier::{ProfileStatus, classify_response};
+use company_url::{CompanyPath, parse_company_path};
+pub use domain::{DomainPolicy, HostVariant};
+pub use entity::{EntityKind, LinkedInEntity, classify_linkedin_url};
+pub use extract::{FoundUrl, extract_linkedin_urls};
+pub use file_cache::FileCache;
+pub use identity::{
+    IdentityMatch, MatchSignal, MatchSignalKind, MatchVerdict, PersonHint, score_identity,
+};
+pub use outcome::ValidationOutcome;
+pub use profile_url::{
+    CanonicalProfileUrl, LegacyFormat, ProfileSection, UsernameRule, normalize_profile_url,
+    profile_section,
+};
+use profile_url::{ProfileTarget, parse_profile_target};
+use repair::repair_for;
+pub use repair::{RepairedUrl, UrlRepair, repair_linkedin_url};
+use retry::Attempts;
+pub use retry::{RetryBackoff, RetryPolicy, RetryableErrors};
 pub use rig_helpers::{
     RigValidationResult, rig_is_valid, rig_validate, rig_validate_json, rig_validate_text,
 };
+pub use rules::{DetectionRule, DetectionRules, DetectionRulesError, RuleMatcher, RuleOutcome};
+use settings::ValidationSettings;
+pub use short_link::{ShortLink, ShortLinkFailure};
+use short_link::{is_short_link, resolve_short_link, resolve_short_link_async};
+pub use suggest::{ProfileSuggestion, SuggestOptions};
+pub use summary::{PublicProfileSummary, parse_profile_summary};
+use throttle::Throttle;
+pub use throttle::{ThrottlePolicy, ThrottleState};
+pub use transport::{
+    AsyncReqwestTransport, AsyncTransport, HttpRequest, HttpResponse, ReqwestTransport,
+    ScriptedTransport, Transport,
+};
 
 /// Errors that can occur during `LinkedIn` URL validation.
 #[derive(Error, Debug)]
@@ -164,7 +226,7 @@ pub enum LinkedInUrlError {
     InvalidUrl(String),
 
     /// The URL is not from `LinkedIn` domain.
-    #[error("[NOT_LINKEDIN_DOMAIN] The URL is not from linkedin.com or www.linkedin.com domain")]
+    #[error("[NOT_LINKEDIN_DOMAIN] The URL is not from an official LinkedIn domain")]
     NotLinkedInUrl,
 
     /// The URL is from `LinkedIn` but not a profile URL.
@@ -173,6 +235,26 @@ pub enum LinkedInUrlError {
     )]
     NotProfileUrl,
 
+    /// The URL is from `LinkedIn` but not a company page URL.
+    #[error(
+        "[NOT_COMPANY_URL] The URL is not a LinkedIn company page URL (expected format: /company/name)"
+    )]
+    NotCompanyUrl,
+
+    /// The profile username breaks one of `LinkedIn`'s username rules.
+    #[error("[INVALID_USERNAME] The LinkedIn profile username is invalid: {0}")]
+    InvalidUsername(UsernameRule),
+
+    /// The URL uses a legacy profile format that can only be resolved over the network.
+    #[error(
+        "[LEGACY_PROFILE_URL] The URL uses the legacy LinkedIn {0} profile format and must be resolved to an /in/ URL"
+    )]
+    LegacyProfileUrl(LegacyFormat),
+
+    /// A `lnkd.in` short link could not be followed to a `LinkedIn` URL.
+    #[error("[SHORT_LINK_UNRESOLVED] The short link could not be resolved: {0}")]
+    UnresolvedShortLink(ShortLinkFailure),
+
     /// Network error occurred during validation.
     #[error("[NETWORK_ERROR] Failed to connect to LinkedIn: {0}")]
     NetworkError(#[from] reqwest::Error),
@@ -190,10 +272,19 @@ pub enum LinkedInUrlError {
     /// HTTP client build error
     #[error("[CLIENT_BUILD_ERROR] Failed to create HTTP client: {0}")]
     ClientBuildError(String),
+
+    /// A custom transport failed to complete the request.
+    #[error("[TRANSPORT_ERROR] The HTTP transport failed: {0}")]
+    TransportError(String),
 }
 
 /// A `LinkedIn` profile validator that performs HTTP requests to verify profile existence.
 ///
+/// The validator is generic over its [`Transport`]. [`LinkedInValidator::new`] uses
+/// [`ReqwestTransport`]; use [`LinkedInValidator::builder`] to configure timeouts,
+/// proxies and headers, or [`LinkedInValidator::with_transport`] to plug in another
+/// implementation such as [`ScriptedTransport`].
+///
 /// # Example
 ///
 /// ```no_run
@@ -202,8 +293,9 @@ pub enum LinkedInUrlError {
 /// let validator = LinkedInValidator::new().expect("Failed to create validator");
 /// let result = validator.is_valid_linkedin_profile_url("https://www.linkedin.com/in/johndoe");
 /// ```
-pub struct LinkedInValidator {
-    client: reqwest::blocking::Client,
+pub struct LinkedInValidator<T = ReqwestTransport> {
+    transport: T,
+    settings: ValidationSettings,
 }
 
 impl LinkedInValidator {
@@ -213,13 +305,176 @@ impl LinkedInValidator {
     ///
     /// Returns an error if the HTTP client cannot be built.
     pub fn new() -> Result<Self, LinkedInUrlError> {
-        let client = reqwest::blocking::Client::builder()
-            .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36")
-            .timeout(std::time::Duration::from_secs(10))
+        Ok(Self::with_transport(ReqwestTransport::new()?))
+    }
+
+    /// Creates a validator that sends `user_agent` instead of the default browser user agent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be built.
+    pub fn new_with_user_agent(user_agent: &str) -> Result<Self, LinkedInUrlError> {
+        LinkedInValidatorBuilder::new()
+            .user_agent(user_agent)
             .build()
-            .map_err(|e| LinkedInUrlError::ClientBuildError(e.to_string()))?;
+    }
+
+    /// Returns a [`LinkedInValidatorBuilder`] for configuring the HTTP client.
+    #[must_use]
+    pub fn builder() -> LinkedInValidatorBuilder {
+        LinkedInValidatorBuilder::new()
+    }
+}
+
+impl<T: Transport> LinkedInValidator<T> {
+    /// Creates a validator that sends its requests through `transport`.
+    pub const fn with_transport(transport: T) -> Self {
+        Self {
+            transport,
+            settings: ValidationSettings::new(),
+        }
+    }
+
+    /// Replaces the policy deciding which hosts are accepted as `LinkedIn`.
+    #[must_use]
+    pub fn with_domain_policy(mut self, domain_policy: DomainPolicy) -> Self {
+        self.settings.domain_policy = domain_policy;
+        self
+    }
+
+    /// Sets whether legacy `/pub/` and `/profile/view?id=` URLs are followed to their
+    /// `/in/` profile. When disabled they fail with [`LinkedInUrlError::LegacyProfileUrl`].
+    #[must_use]
+    pub const fn with_legacy_url_resolution(mut self, resolve: bool) -> Self {
+        self.settings.resolve_legacy_urls = resolve;
+        self
+    }
+
+    /// Sets whether `lnkd.in` short links are followed before the profile checks.
+    ///
+    /// Disabled by default. The transport should return redirect responses for
+    /// [`HttpRequest::without_redirects`] requests so every hop is counted; see
+    /// [`LinkedInValidatorBuilder::resolve_short_links`].
+    #[must_use]
+    pub const fn with_short_link_resolution(mut self, resolve: bool) -> Self {
+        self.settings.resolve_short_links = resolve;
+        self
+    }
+
+    /// Sets how many redirects are followed while resolving a short link. Defaults to 5.
+    #[must_use]
+    pub const fn with_max_short_link_hops(mut self, max_hops: usize) -> Self {
+        self.settings.max_short_link_hops = max_hops;
+        self
+    }
 
-        Ok(Self { client })
+    /// Sets whether [`ai_validate`](Self::ai_validate) repairs malformed input with
+    /// [`repair_linkedin_url`] first. Accepted results then report the repairs and a
+    /// reduced confidence. Disabled by default.
+    #[must_use]
+    pub const fn with_url_repair(mut self, repair: bool) -> Self {
+        self.settings.repair_urls = repair;
+        self
+    }
+
+    /// Sets whether the public details of served profile pages are read into
+    /// [`ValidationOutcome::summary`] and [`AIValidationResult::profile_summary`].
+    /// Disabled by default.
+    #[must_use]
+    pub const fn with_profile_summary(mut self, fetch: bool) -> Self {
+        self.settings.fetch_profile_summary = fetch;
+        self
+    }
+
+    /// Replaces the rules deciding whether a response shows, hides or lacks a profile.
+    ///
+    /// See [`LinkedInValidatorBuilder::detection_rules`].
+    #[must_use]
+    pub fn with_detection_rules(mut self, rules: DetectionRules) -> Self {
+        self.settings.detection_rules = Some(Arc::new(rules));
+        self
+    }
+
+    /// Sets the `Accept-Language` header sent with page requests.
+    ///
+    /// See [`LinkedInValidatorBuilder::accept_language`].
+    #[must_use]
+    pub fn with_accept_language(mut self, language: impl Into<String>) -> Self {
+        self.settings.accept_language = Some(language.into());
+        self
+    }
+
+    /// Enables adaptive backoff and the circuit breaker described by `policy`.
+    ///
+    /// See [`LinkedInValidatorBuilder::throttle`].
+    #[must_use]
+    pub fn with_throttle(mut self, policy: ThrottlePolicy) -> Self {
+        self.settings.throttle = Some(Arc::new(Throttle::new(policy)));
+        self
+    }
+
+    /// Retries page requests that fail for transient reasons as described by `policy`.
+    ///
+    /// See [`LinkedInValidatorBuilder::retry`].
+    #[must_use]
+    pub fn with_retry(mut self, policy: RetryPolicy) -> Self {
+        self.settings.retry = Some(policy);
+        self
+    }
+
+    /// Caches [`ai_validate`](Self::ai_validate) results as described by `policy`.
+    ///
+    /// See [`LinkedInValidatorBuilder::cache`].
+    #[must_use]
+    pub fn with_cache(mut self, policy: CachePolicy) -> Self {
+        self.settings.cache = Some(Arc::new(ResultCache::new(policy)));
+        self
+    }
+
+    /// Caches [`ai_validate`](Self::ai_validate) results in `store` as described by
+    /// `policy`, for example in a [`FileCache`] shared with other processes.
+    ///
+    /// See [`LinkedInValidatorBuilder::cache_store`].
+    #[must_use]
+    pub fn with_cache_store(
+        mut self,
+        policy: CachePolicy,
+        store: impl ValidationCache + 'static,
+    ) -> Self {
+        self.settings.cache = Some(Arc::new(ResultCache::with_store(policy, Arc::new(store))));
+        self
+    }
+
+    /// Forgets every cached result. Does nothing if no [`CachePolicy`] is set.
+    pub fn clear_cache(&self) {
+        if let Some(cache) = &self.settings.cache {
+            cache.clear();
+        }
+    }
+
+    pub(crate) fn with_settings(mut self, settings: ValidationSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    /// Returns the transport used by this validator.
+    pub const fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// Returns what the validator has seen of `LinkedIn`'s throttling, if a
+    /// [`ThrottlePolicy`] is set.
+    #[must_use]
+    pub fn throttle_state(&self) -> Option<ThrottleState> {
+        self.settings
+            .throttle
+            .as_ref()
+            .map(|throttle| throttle.state())
+    }
+
+    /// Returns the policy deciding which hosts are accepted as `LinkedIn`.
+    pub const fn domain_policy(&self) -> &DomainPolicy {
+        &self.settings.domain_policy
     }
 
     /// Validates a `LinkedIn` profile URL by checking format and existence.
@@ -257,79 +512,326 @@ impl LinkedInValidator {
     /// }
     /// ```
     pub fn is_valid_linkedin_profile_url(&self, url_str: &str) -> Result<bool, LinkedInUrlError> {
-        let url = Url::parse(url_str).map_err(|e| LinkedInUrlError::InvalidUrl(e.to_string()))?;
+        self.validate(url_str)?.status.into_result()
+    }
 
-        if !is_linkedin_domain(&url) {
-            return Err(LinkedInUrlError::NotLinkedInUrl);
-        }
+    /// Checks a `LinkedIn` profile URL and reports what `LinkedIn` said about it.
+    ///
+    /// Unlike [`is_valid_linkedin_profile_url`](Self::is_valid_linkedin_profile_url), a
+    /// missing, hidden or rate limited profile is not an error: it is reported through
+    /// [`ValidationOutcome::status`]. Legacy `/pub/` and `/profile/view?id=` URLs are
+    /// fetched as-is and the `/in/` profile they redirect to is reported as the
+    /// [canonical URL](ValidationOutcome::canonical_url). With short link resolution
+    /// enabled, `lnkd.in` links are followed first and recorded in
+    /// [`ValidationOutcome::short_link`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The URL format is invalid
+    /// - The URL is not from `LinkedIn` domain
+    /// - The URL is not a profile URL
+    /// - The URL is a legacy profile URL and legacy resolution is disabled
+    /// - The URL is a short link that does not lead to a `LinkedIn` URL
+    /// - Network request fails
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use credify::{LinkedInValidator, ProfileStatus};
+    ///
+    /// let validator = LinkedInValidator::new().expect("Failed to create validator");
+    /// let outcome = validator.validate("https://www.linkedin.com/in/johndoe").expect("Validation failed");
+    /// match outcome.status {
+    ///     ProfileStatus::Exists => println!("Profile exists"),
+    ///     ProfileStatus::LikelyExists { reason } => println!("Profile probably exists: {}", reason),
+    ///     ProfileStatus::NotFound => println!("Profile not found"),
+    ///     ProfileStatus::RateLimited { retry_after } => println!("Rate limited: {:?}", retry_after),
+    ///     ProfileStatus::Blocked | ProfileStatus::Unknown => println!("Could not check the profile"),
+    /// }
+    /// ```
+    pub fn validate(&self, url_str: &str) -> Result<ValidationOutcome, LinkedInUrlError> {
+        self.validate_counting(url_str, &mut Attempts::new())
+    }
 
-        if !is_profile_path(&url) {
-            return Err(LinkedInUrlError::NotProfileUrl);
-        }
+    /// [`validate`](Self::validate), counting the page requests in `attempts`.
+    fn validate_counting(
+        &self,
+        url_str: &str,
+        attempts: &mut Attempts,
+    ) -> Result<ValidationOutcome, LinkedInUrlError> {
+        let started = Instant::now();
+        let short_link = self.follow_short_link(url_str)?;
+        let target = short_link
+            .as_ref()
+            .map_or(url_str, |link| link.resolved_url.as_str());
+        let (profile, host_variant) = parse_profile_url(target, &self.settings)?;
+        let response = self.fetch_page(profile.request_url(), attempts)?;
+
+        Ok(ValidationOutcome::from_response(
+            profile,
+            host_variant,
+            &response,
+            self.settings.detection_rules(),
+            started.elapsed(),
+        )
+        .with_attempts(attempts)
+        .with_short_link(short_link)
+        .with_summary(&response, &self.settings))
+    }
 
-        self.check_profile_exists(url_str)?;
+    /// Checks a `LinkedIn` company page URL such as `https://www.linkedin.com/company/microsoft`.
+    ///
+    /// Uses the same 404 and authwall detection as [`validate`](Self::validate). Tabs such
+    /// as `/company/<slug>/about/` are checked against the company page itself, and the
+    /// returned outcome has [`entity`](ValidationOutcome::entity) set to
+    /// [`EntityKind::Company`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The URL format is invalid
+    /// - The URL is not from `LinkedIn` domain
+    /// - The URL is not a company page URL
+    /// - Network request fails
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use credify::{EntityKind, HttpResponse, LinkedInValidator, ProfileStatus, ScriptedTransport};
+    ///
+    /// let transport = ScriptedTransport::new();
+    /// transport.enqueue(HttpResponse::new(200, "https://www.linkedin.com/company/microsoft"));
+    ///
+    /// let validator = LinkedInValidator::with_transport(transport);
+    /// let outcome = validator
+    ///     .validate_company_url("https://linkedin.com/company/Microsoft/about/")
+    ///     .unwrap();
+    ///
+    /// assert_eq!(outcome.entity, EntityKind::Company);
+    /// assert_eq!(outcome.status, ProfileStatus::Exists);
+    /// assert_eq!(outcome.canonical_url, "https://www.linkedin.com/company/microsoft");
+    /// ```
+    pub fn validate_company_url(
+        &self,
+        url_str: &str,
+    ) -> Result<ValidationOutcome, LinkedInUrlError> {
+        self.validate_company_counting(url_str, &mut Attempts::new())
+    }
 
-        Ok(true)
+    /// [`validate_company_url`](Self::validate_company_url), counting the page requests in
+    /// `attempts`.
+    fn validate_company_counting(
+        &self,
+        url_str: &str,
+        attempts: &mut Attempts,
+    ) -> Result<ValidationOutcome, LinkedInUrlError> {
+        let started = Instant::now();
+        let short_link = self.follow_short_link(url_str)?;
+        let target = short_link
+            .as_ref()
+            .map_or(url_str, |link| link.resolved_url.as_str());
+        let (company, host_variant) = parse_company_url(target, &self.settings)?;
+        let response = self.fetch_page(&company.canonical, attempts)?;
+
+        Ok(ValidationOutcome::from_company_response(
+            company,
+            host_variant,
+            &response,
+            self.settings.detection_rules(),
+            started.elapsed(),
+        )
+        .with_attempts(attempts)
+        .with_short_link(short_link))
     }
 
-    fn check_profile_exists(&self, url: &str) -> Result<(), LinkedInUrlError> {
-        let mut response = self.client.get(url).send()?;
+    /// Resolves `url_str` if it is a short link the settings allow following.
+    fn follow_short_link(&self, url_str: &str) -> Result<Option<ShortLink>, LinkedInUrlError> {
+        short_link_target(url_str, &self.settings)
+            .map(|max_hops| {
+                resolve_short_link(
+                    &self.transport,
+                    url_str,
+                    &self.settings.domain_policy,
+                    max_hops,
+                )
+            })
+            .transpose()
+    }
 
-        // LinkedIn returns 999 status for bot detection/rate limiting
-        // In this case, we need to follow redirects manually
-        if response.status().as_u16() == 999 {
-            // Try with cookie header to bypass authwall
-            response = self.client.get(url).header("Cookie", "sl=v=1&1").send()?;
+    /// Fetches `url`, backing off and retrying blocked requests when throttling is enabled.
+    fn fetch_page(
+        &self,
+        url: &str,
+        attempts: &mut Attempts,
+    ) -> Result<HttpResponse, LinkedInUrlError> {
+        let Some(throttle) = &self.settings.throttle else {
+            return self.fetch_with_retry(url, attempts);
+        };
+        let mut attempt = 0;
+        loop {
+            if let Some(pause) = throttle.pause() {
+                thread::sleep(pause);
+            }
+            let response = self.fetch_with_retry(url, attempts)?;
+            match throttle.record(&response, self.settings.detection_rules(), attempt) {
+                Some(delay) => thread::sleep(delay),
+                None => return Ok(response),
+            }
+            attempt += 1;
         }
+    }
 
-        // Check if redirected to 404 page
-        let final_url = response.url().to_string();
-        if final_url.contains("/404/") || final_url.contains("linkedin.com/404") {
-            return Err(LinkedInUrlError::ProfileNotFound);
+    /// Fetches `url`, retrying transient failures when a retry policy is set.
+    fn fetch_with_retry(
+        &self,
+        url: &str,
+        attempts: &mut Attempts,
+    ) -> Result<HttpResponse, LinkedInUrlError> {
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            attempts.count += 1;
+            let result = self.fetch_page_once(url, attempts);
+            let delay = self
+                .settings
+                .retry
+                .as_ref()
+                .and_then(|retry| retry.delay_after(&result, attempt, attempts.started));
+            match delay {
+                Some(delay) => thread::sleep(delay),
+                None => return result,
+            }
         }
+    }
 
-        // Get response body
-        let body = response.text()?;
+    /// Fetches `url`, retrying with the bypass cookie when `LinkedIn` answers 999.
+    ///
+    /// The cookie retry is counted in `attempts`.
+    fn fetch_page_once(
+        &self,
+        url: &str,
+        attempts: &mut Attempts,
+    ) -> Result<HttpResponse, LinkedInUrlError> {
+        let response = self.transport.fetch(&self.settings.page_request(url))?;
 
-        // Check for authwall (indicates we're being blocked)
-        if body.contains("/authwall") || body.contains("sessionRedirect") {
-            // When we hit authwall, we can't determine if profile exists
-            return Err(LinkedInUrlError::AuthenticationRequired);
+        // LinkedIn returns 999 status for bot detection/rate limiting
+        if response.status == BOT_DETECTION_STATUS {
+            attempts.count += 1;
+            // Try with cookie header to bypass authwall
+            return self.transport.fetch(
+                &self
+                    .settings
+                    .page_request(url)
+                    .header("Cookie", BYPASS_COOKIE),
+            );
         }
+        Ok(response)
+    }
 
-        // Check for common error page indicators
-        // LinkedIn uses various formats for non-existent pages
-        if body.contains("This page doesn't exist")
-            || body.contains("This page doesn't exist")  // UTF-8 curly quote variant
-            || body.contains("This page doesn&#39;t exist")  // HTML encoded apostrophe
-            || body.contains("This page doesn&apos;t exist")  // XML encoded apostrophe
-            || body.contains("Page not found")
-            || body.contains("Check the URL or return to LinkedIn home")
-            || body.contains("Check your URL or return to LinkedIn home")  // Variant with "your"
-            || body.contains("return to LinkedIn home")
-            || (body.contains("Go to your feed") && body.contains("doesn't exist"))
-            || (body.contains("Go to your feed") && body.contains("doesn&#39;t exist"))
-            || (body.contains("Go to your feed") && body.contains("doesn&apos;t exist"))
-        {
-            return Err(LinkedInUrlError::ProfileNotFound);
+    /// Validates a URL and returns the structured result for AI agents.
+    ///
+    /// See [`ai_validate`].
+    pub fn ai_validate(&self, url: &str) -> AIValidationResult {
+        let repaired = repair_for(url, &self.settings);
+        let url = repaired
+            .as_ref()
+            .map_or(url, |repaired| repaired.url.as_str());
+        let cached = cache::lookup_key(url, &self.settings);
+        if let Some(hit) = cached.as_ref().and_then(|(cache, key)| cache.hit(key)) {
+            return report::after_repair(hit, repaired);
         }
+        let mut attempts = Attempts::new();
+        let mut result = report::ai_result(url, &self.settings, || {
+            self.validate_counting(url, &mut attempts)
+        });
+        result.metadata.attempts = attempts.reported();
+        if let Some((cache, key)) = cached {
+            cache.insert(&key.canonical_url, &result);
+        }
+        report::after_repair(result, repaired)
+    }
+
+    /// Validates a URL and returns the AI result serialized as JSON.
+    ///
+    /// See [`ai_validate_json`].
+    pub fn ai_validate_json(&self, url: &str) -> String {
+        report::ai_json(&self.ai_validate(url))
+    }
+
+    /// Validates a URL and returns the verbose report for LLM consumption.
+    ///
+    /// See [`validate_for_llm`].
+    pub fn validate_for_llm(&self, url: &str) -> String {
+        report::llm_report(url, self.validate(url))
+    }
+
+    /// Validates a URL and scores whether the profile belongs to `expected`.
+    ///
+    /// Name, company, title and location are only compared when the validator fetches
+    /// profile summaries; see [`score_identity`].
+    pub fn match_profile(&self, expected: &PersonHint, url: &str) -> IdentityMatch {
+        score_identity(expected, self.ai_validate(url))
+    }
 
-        Ok(())
+    /// Generates candidate profile URLs for a person and validates the highest ranked ones.
+    ///
+    /// See [`suggest_profile_urls`].
+    pub fn suggest_profile_urls(
+        &self,
+        first: &str,
+        last: &str,
+        options: &SuggestOptions,
+    ) -> Vec<ProfileSuggestion> {
+        let mut suggestions = suggest::candidates(first, last, options);
+        for suggestion in suggestions.iter_mut().take(options.max_validations()) {
+            suggestion.validation = Some(self.ai_validate(&suggestion.url));
+        }
+        suggestions
     }
 }
 
-fn is_linkedin_domain(url: &Url) -> bool {
-    matches!(url.domain(), Some(domain) if domain == "linkedin.com" || domain == "www.linkedin.com")
+/// Returns the hop limit if `url_str` is a short link that `settings` resolves.
+fn short_link_target(url_str: &str, settings: &ValidationSettings) -> Option<usize> {
+    let url = Url::parse(url_str).ok()?;
+    (settings.resolve_short_links && is_short_link(&url)).then_some(settings.max_short_link_hops)
 }
 
-static PROFILE_REGEX: Lazy<Regex> = Lazy::new(|| {
-    Regex::new(r"^/in/[a-zA-Z0-9\-\.]+/?$")
-        .expect("[INTERNAL_ERROR] Failed to compile profile regex pattern")
-});
+/// Parses `url_str` and checks that it has the shape of a `LinkedIn` company page URL.
+fn parse_company_url(
+    url_str: &str,
+    settings: &ValidationSettings,
+) -> Result<(CompanyPath, HostVariant), LinkedInUrlError> {
+    let url = Url::parse(url_str).map_err(|e| LinkedInUrlError::InvalidUrl(e.to_string()))?;
 
-fn is_profile_path(url: &Url) -> bool {
-    let path = url.path();
-    PROFILE_REGEX.is_match(path)
+    let host_variant = settings
+        .domain_policy
+        .url_host_variant(&url)
+        .ok_or(LinkedInUrlError::NotLinkedInUrl)?;
+
+    Ok((parse_company_path(&url)?, host_variant))
+}
+
+/// Parses `url_str` and checks that it has the shape of a `LinkedIn` profile URL.
+///
+/// Legacy profile URLs are rejected unless the settings resolve them.
+fn parse_profile_url(
+    url_str: &str,
+    settings: &ValidationSettings,
+) -> Result<(ProfileTarget, HostVariant), LinkedInUrlError> {
+    let url = Url::parse(url_str).map_err(|e| LinkedInUrlError::InvalidUrl(e.to_string()))?;
+
+    let host_variant = settings
+        .domain_policy
+        .url_host_variant(&url)
+        .ok_or(LinkedInUrlError::NotLinkedInUrl)?;
+
+    match parse_profile_target(&url)? {
+        ProfileTarget::Legacy(legacy) if !settings.resolve_legacy_urls => {
+            Err(LinkedInUrlError::LegacyProfileUrl(legacy.format))
+        }
+        profile => Ok((profile, host_variant)),
+    }
 }
 
 impl LinkedInValidator {
@@ -346,7 +848,8 @@ impl LinkedInValidator {
 /// Validates a `LinkedIn` profile URL asynchronously.
 ///
 /// This function performs an HTTP request to verify if the profile actually exists.
-/// Use this for async contexts like web servers.
+/// Use this for async contexts like web servers. Requests go through a shared
+/// [`AsyncLinkedInValidator`], so connections are pooled across calls.
 ///
 /// # Arguments
 ///
@@ -380,62 +883,232 @@ impl LinkedInValidator {
 /// # }
 /// ```
 pub async fn validate_linkedin_url_async(url: &str) -> Result<bool, LinkedInUrlError> {
-    let url_parsed = Url::parse(url).map_err(|e| LinkedInUrlError::InvalidUrl(e.to_string()))?;
+    default_async_validator()?
+        .is_valid_linkedin_profile_url(url)
+        .await
+}
 
-    if !is_linkedin_domain(&url_parsed) {
-        return Err(LinkedInUrlError::NotLinkedInUrl);
-    }
+/// Checks a `LinkedIn` company page URL such as `https://www.linkedin.com/company/microsoft`.
+///
+/// See [`LinkedInValidator::validate_company_url`].
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The HTTP client cannot be built
+/// - The URL format is invalid
+/// - The URL is not from `LinkedIn` domain
+/// - The URL is not a company page URL
+/// - Network request fails
+///
+/// # Example
+///
+/// ```no_run
+/// use credify::{ProfileStatus, validate_company_url};
+///
+/// let outcome = validate_company_url("https://www.linkedin.com/company/microsoft")
+///     .expect("Validation failed");
+/// if outcome.status == ProfileStatus::NotFound {
+///     println!("No such company page");
+/// }
+/// ```
+pub fn validate_company_url(url: &str) -> Result<ValidationOutcome, LinkedInUrlError> {
+    LinkedInValidator::new()?.validate_company_url(url)
+}
 
-    if !is_profile_path(&url_parsed) {
-        return Err(LinkedInUrlError::NotProfileUrl);
-    }
+/// Checks a `LinkedIn` company page URL asynchronously.
+///
+/// See [`AsyncLinkedInValidator::validate_company_url`]. Requests go through the shared
+/// async validator.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The HTTP client cannot be built
+/// - The URL format is invalid
+/// - The URL is not from `LinkedIn` domain
+/// - The URL is not a company page URL
+/// - Network request fails
+pub async fn validate_company_url_async(url: &str) -> Result<ValidationOutcome, LinkedInUrlError> {
+    default_async_validator()?.validate_company_url(url).await
+}
 
-    let client = reqwest::Client::builder()
-        .user_agent("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36")
-        .timeout(std::time::Duration::from_secs(10))
-        .build()
-        .map_err(|e| LinkedInUrlError::ClientBuildError(e.to_string()))?;
+/// Validates a `LinkedIn` profile URL asynchronously through a custom [`AsyncTransport`].
+///
+/// Behaves exactly like [`validate_linkedin_url_async`] but sends its requests
+/// through `transport`, which allows running the validation against a local
+/// stand-in such as [`ScriptedTransport`].
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate_linkedin_url_async`].
+///
+/// # Example
+///
+/// ```
+/// use credify::{HttpResponse, LinkedInUrlError, ScriptedTransport, validate_linkedin_url_with_transport};
+///
+/// # async fn example() {
+/// let transport = ScriptedTransport::new();
+/// transport.enqueue(HttpResponse::new(200, "https://www.linkedin.com/404/"));
+///
+/// let result = validate_linkedin_url_with_transport(&transport, "https://www.linkedin.com/in/nobody").await;
+/// assert!(matches!(result, Err(LinkedInUrlError::ProfileNotFound)));
+/// # }
+/// ```
+pub async fn validate_linkedin_url_with_transport<T: AsyncTransport>(
+    transport: &T,
+    url: &str,
+) -> Result<bool, LinkedInUrlError> {
+    validate_with_transport(
+        transport,
+        &ValidationSettings::new(),
+        url,
+        &mut Attempts::new(),
+    )
+    .await?
+    .status
+    .into_result()
+}
 
-    let mut response = client.get(url).send().await?;
+/// Async core behind [`AsyncLinkedInValidator::validate`].
+///
+/// Page requests are counted in `attempts`.
+pub(crate) async fn validate_with_transport<T: AsyncTransport>(
+    transport: &T,
+    settings: &ValidationSettings,
+    url_str: &str,
+    attempts: &mut Attempts,
+) -> Result<ValidationOutcome, LinkedInUrlError> {
+    let started = Instant::now();
+    let short_link = follow_short_link_async(transport, settings, url_str).await?;
+    let target = short_link
+        .as_ref()
+        .map_or(url_str, |link| link.resolved_url.as_str());
+    let (profile, host_variant) = parse_profile_url(target, settings)?;
+    let response = fetch_page_async(transport, settings, profile.request_url(), attempts).await?;
+
+    Ok(ValidationOutcome::from_response(
+        profile,
+        host_variant,
+        &response,
+        settings.detection_rules(),
+        started.elapsed(),
+    )
+    .with_attempts(attempts)
+    .with_short_link(short_link)
+    .with_summary(&response, settings))
+}
 
-    // LinkedIn returns 999 status for bot detection/rate limiting
-    if response.status().as_u16() == 999 {
-        // Try with cookie header to bypass authwall
-        response = client.get(url).header("Cookie", "sl=v=1&1").send().await?;
-    }
+/// Async core behind [`AsyncLinkedInValidator::validate_company_url`].
+///
+/// Page requests are counted in `attempts`.
+pub(crate) async fn validate_company_with_transport<T: AsyncTransport>(
+    transport: &T,
+    settings: &ValidationSettings,
+    url_str: &str,
+    attempts: &mut Attempts,
+) -> Result<ValidationOutcome, LinkedInUrlError> {
+    let started = Instant::now();
+    let short_link = follow_short_link_async(transport, settings, url_str).await?;
+    let target = short_link
+        .as_ref()
+        .map_or(url_str, |link| link.resolved_url.as_str());
+    let (company, host_variant) = parse_company_url(target, settings)?;
+    let response = fetch_page_async(transport, settings, &company.canonical, attempts).await?;
+
+    Ok(ValidationOutcome::from_company_response(
+        company,
+        host_variant,
+        &response,
+        settings.detection_rules(),
+        started.elapsed(),
+    )
+    .with_attempts(attempts)
+    .with_short_link(short_link))
+}
 
-    // Check if redirected to 404 page
-    let final_url = response.url().to_string();
-    if final_url.contains("/404/") || final_url.contains("linkedin.com/404") {
-        return Err(LinkedInUrlError::ProfileNotFound);
+/// Resolves `url_str` if it is a short link the settings allow following.
+async fn follow_short_link_async<T: AsyncTransport>(
+    transport: &T,
+    settings: &ValidationSettings,
+    url_str: &str,
+) -> Result<Option<ShortLink>, LinkedInUrlError> {
+    match short_link_target(url_str, settings) {
+        Some(max_hops) => Ok(Some(
+            resolve_short_link_async(transport, url_str, &settings.domain_policy, max_hops).await?,
+        )),
+        None => Ok(None),
     }
+}
 
-    // Get response body
-    let body = response.text().await?;
-
-    // Check for authwall (indicates we're being blocked)
-    if body.contains("/authwall") || body.contains("sessionRedirect") {
-        return Err(LinkedInUrlError::AuthenticationRequired);
+/// Fetches `url`, backing off and retrying blocked requests when throttling is enabled.
+async fn fetch_page_async<T: AsyncTransport>(
+    transport: &T,
+    settings: &ValidationSettings,
+    url: &str,
+    attempts: &mut Attempts,
+) -> Result<HttpResponse, LinkedInUrlError> {
+    let Some(throttle) = &settings.throttle else {
+        return fetch_with_retry_async(transport, settings, url, attempts).await;
+    };
+    let mut attempt = 0;
+    loop {
+        if let Some(pause) = throttle.pause() {
+            tokio::time::sleep(pause).await;
+        }
+        let response = fetch_with_retry_async(transport, settings, url, attempts).await?;
+        match throttle.record(&response, settings.detection_rules(), attempt) {
+            Some(delay) => tokio::time::sleep(delay).await,
+            None => return Ok(response),
+        }
+        attempt += 1;
     }
+}
 
-    // Check for common error page indicators
-    // LinkedIn uses various formats for non-existent pages
-    if body.contains("This page doesn't exist")
-        || body.contains("This page doesn't exist")  // UTF-8 curly quote variant
-        || body.contains("This page doesn&#39;t exist")  // HTML encoded apostrophe
-        || body.contains("This page doesn&apos;t exist")  // XML encoded apostrophe
-        || body.contains("Page not found")
-        || body.contains("Check the URL or return to LinkedIn home")
-        || body.contains("Check your URL or return to LinkedIn home")  // Variant with "your"
-        || body.contains("return to LinkedIn home")
-        || (body.contains("Go to your feed") && body.contains("doesn't exist"))
-        || (body.contains("Go to your feed") && body.contains("doesn&#39;t exist"))
-        || (body.contains("Go to your feed") && body.contains("doesn&apos;t exist"))
-    {
-        return Err(LinkedInUrlError::ProfileNotFound);
+/// Fetches `url`, retrying transient failures when a retry policy is set.
+async fn fetch_with_retry_async<T: AsyncTransport>(
+    transport: &T,
+    settings: &ValidationSettings,
+    url: &str,
+    attempts: &mut Attempts,
+) -> Result<HttpResponse, LinkedInUrlError> {
+    let mut attempt = 0;
+    loop {
+        attempt += 1;
+        attempts.count += 1;
+        let result = fetch_page_once_async(transport, settings, url, attempts).await;
+        let delay = settings
+            .retry
+            .as_ref()
+            .and_then(|retry| retry.delay_after(&result, attempt, attempts.started));
+        match delay {
+            Some(delay) => tokio::time::sleep(delay).await,
+            None => return result,
+        }
     }
+}
 
-    Ok(true)
+/// Fetches `url`, retrying with the bypass cookie when `LinkedIn` answers 999.
+///
+/// The cookie retry is counted in `attempts`.
+async fn fetch_page_once_async<T: AsyncTransport>(
+    transport: &T,
+    settings: &ValidationSettings,
+    url: &str,
+    attempts: &mut Attempts,
+) -> Result<HttpResponse, LinkedInUrlError> {
+    let response = transport.fetch(&settings.page_request(url)).await?;
+
+    // LinkedIn returns 999 status for bot detection/rate limiting
+    if response.status == BOT_DETECTION_STATUS {
+        attempts.count += 1;
+        // Try with cookie header to bypass authwall
+        return transport
+            .fetch(&settings.page_request(url).header("Cookie", BYPASS_COOKIE))
+            .await;
+    }
+    Ok(response)
 }
 
 /// Validates a LinkedIn profile URL and returns a structured string for LLM consumption.
@@ -492,300 +1165,7 @@ pub async fn validate_linkedin_url_async(url: &str) -> Result<bool, LinkedInUrlE
 /// // === END OF VALIDATION REPORT ===
 /// ```
 pub fn validate_for_llm(url: &str) -> String {
-    let mut result = String::new();
-
-    // Header
-    result.push_str("=== LINKEDIN PROFILE VALIDATION REPORT ===\n\n");
-    result.push_str(&format!("TIMESTAMP: {}\n", chrono::Utc::now().to_rfc3339()));
-    result.push_str(&format!("INPUT_URL: {url}\n"));
-    result.push('\n');
-
-    let validator = match LinkedInValidator::new() {
-        Ok(v) => v,
-        Err(e) => {
-            result.push_str("VALIDATION_RESULT: ERROR\n");
-            result.push_str("VALIDATION_STATUS: FAILED\n");
-            result.push_str("ERROR_TYPE: VALIDATOR_INITIALIZATION_FAILED\n");
-            result.push_str(&format!("ERROR_MESSAGE: {e}\n"));
-            result.push_str("ERROR_SEVERITY: CRITICAL\n");
-            result.push_str("\nDETAILED_EXPLANATION:\n");
-            result.push_str(
-                "The HTTP client required for LinkedIn validation could not be initialized. ",
-            );
-            result.push_str("This is typically caused by system resource constraints or TLS configuration issues.\n");
-            result.push_str("\nSUGGESTED_ACTIONS:\n");
-            result.push_str("1. Check available system memory and ensure sufficient resources\n");
-            result.push_str("2. Verify TLS/SSL libraries are properly installed on the system\n");
-            result
-                .push_str("3. Check for any system-level network restrictions or firewall rules\n");
-            result.push_str("4. Try restarting the application or service\n");
-            result.push_str(
-                "5. If problem persists, check system logs for more detailed error information\n",
-            );
-            result.push_str("\nRECOMMENDED_NEXT_STEP: Resolve system-level issues before attempting validation again\n");
-            result.push_str("\n=== END OF VALIDATION REPORT ===\n");
-            return result;
-        }
-    };
-
-    match validator.is_valid_linkedin_profile_url(url) {
-        Ok(_) => {
-            result.push_str("VALIDATION_RESULT: SUCCESS\n");
-            result.push_str("VALIDATION_STATUS: PASSED\n");
-            result.push_str("PROFILE_EXISTS: TRUE\n");
-            result.push_str("URL_FORMAT: VALID\n");
-            result.push_str("DOMAIN_VERIFIED: TRUE\n");
-            result.push_str("PROFILE_ACCESSIBLE: TRUE\n");
-
-            // Extract username from URL
-            if let Ok(parsed_url) = url::Url::parse(url) {
-                if let Some(mut path_segments) = parsed_url.path_segments() {
-                    if let Some(username) = path_segments.next_back() {
-                        result.push_str(&format!(
-                            "LINKEDIN_USERNAME: {}\n",
-                            username.trim_end_matches('/')
-                        ));
-                    }
-                }
-            }
-
-            result.push_str("\nDETAILED_EXPLANATION:\n");
-            result.push_str("The provided URL has been successfully validated. The LinkedIn profile exists and is accessible. ");
-            result.push_str("The URL follows the correct LinkedIn profile format and the domain has been verified as authentic. ");
-            result.push_str("The profile page returned a successful response, confirming the profile is active and publicly viewable.\n");
-
-            result.push_str("\nSUGGESTED_ACTIONS:\n");
-            result.push_str("1. Proceed with profile data extraction using LinkedIn API or web scraping tools\n");
-            result.push_str("2. Cache this validation result to avoid repeated network requests\n");
-            result.push_str(
-                "3. Store the profile URL in your database as a verified LinkedIn profile\n",
-            );
-            result.push_str(
-                "4. Consider extracting additional profile metadata (name, headline, etc.)\n",
-            );
-            result.push_str(
-                "5. Set up monitoring to periodically re-validate the profile existence\n",
-            );
-
-            result.push_str("\nRECOMMENDED_NEXT_STEP: Extract profile data using appropriate LinkedIn data extraction methods\n");
-        }
-        Err(e) => {
-            result.push_str("VALIDATION_RESULT: ERROR\n");
-            result.push_str("VALIDATION_STATUS: FAILED\n");
-
-            match e {
-                LinkedInUrlError::InvalidUrl(ref msg) => {
-                    result.push_str("ERROR_TYPE: INVALID_URL_FORMAT\n");
-                    result.push_str(&format!("ERROR_MESSAGE: {e}\n"));
-                    result.push_str(&format!("ERROR_DETAILS: {msg}\n"));
-                    result.push_str("ERROR_SEVERITY: HIGH\n");
-                    result.push_str("PROFILE_EXISTS: UNKNOWN\n");
-                    result.push_str("URL_FORMAT: INVALID\n");
-
-                    result.push_str("\nDETAILED_EXPLANATION:\n");
-                    result.push_str("The provided string is not a valid URL. The URL parser failed to interpret the input as a properly formatted URL. ");
-                    result.push_str("Common causes include missing protocol (http/https), invalid characters, or malformed structure.\n");
-
-                    result.push_str("\nSUGGESTED_ACTIONS:\n");
-                    result.push_str("1. Ensure the URL starts with 'https://' or 'http://'\n");
-                    result.push_str("2. Check for special characters that need URL encoding\n");
-                    result.push_str("3. Verify there are no spaces or line breaks in the URL\n");
-                    result.push_str(
-                        "4. Confirm the URL follows standard format: protocol://domain/path\n",
-                    );
-                    result.push_str(
-                        "5. Try URL encoding the input if it contains special characters\n",
-                    );
-                    result.push_str(
-                        "6. Example valid format: https://www.linkedin.com/in/username\n",
-                    );
-
-                    result.push_str(
-                        "\nRECOMMENDED_NEXT_STEP: Fix the URL format and retry validation\n",
-                    );
-                }
-                LinkedInUrlError::NotLinkedInUrl => {
-                    result.push_str("ERROR_TYPE: NOT_LINKEDIN_DOMAIN\n");
-                    result.push_str(&format!("ERROR_MESSAGE: {e}\n"));
-                    result.push_str("ERROR_SEVERITY: MEDIUM\n");
-                    result.push_str("PROFILE_EXISTS: NOT_APPLICABLE\n");
-                    result.push_str("URL_FORMAT: VALID\n");
-                    result.push_str("DOMAIN_VERIFIED: FALSE\n");
-
-                    // Extract the actual domain from URL
-                    if let Ok(parsed_url) = url::Url::parse(url) {
-                        if let Some(domain) = parsed_url.domain() {
-                            result.push_str(&format!("ACTUAL_DOMAIN: {domain}\n"));
-                        }
-                    }
-
-                    result.push_str("\nDETAILED_EXPLANATION:\n");
-                    result
-                        .push_str("The URL is properly formatted but does not point to LinkedIn. ");
-                    result.push_str("Only URLs from 'linkedin.com' or 'www.linkedin.com' domains are accepted for LinkedIn profile validation. ");
-                    result.push_str("The provided URL points to a different domain.\n");
-
-                    result.push_str("\nSUGGESTED_ACTIONS:\n");
-                    result.push_str("1. Verify the URL is meant to be a LinkedIn profile URL\n");
-                    result.push_str("2. Check if the URL was copied correctly from LinkedIn\n");
-                    result
-                        .push_str("3. Ensure the domain is 'linkedin.com' or 'www.linkedin.com'\n");
-                    result.push_str("4. Look for the correct LinkedIn profile URL in the user's social media links\n");
-                    result.push_str(
-                        "5. Ask the user to provide their LinkedIn profile URL directly\n",
-                    );
-
-                    result.push_str("\nRECOMMENDED_NEXT_STEP: Obtain the correct LinkedIn profile URL from the user or source\n");
-                }
-                LinkedInUrlError::NotProfileUrl => {
-                    result.push_str("ERROR_TYPE: NOT_PROFILE_URL\n");
-                    result.push_str(&format!("ERROR_MESSAGE: {e}\n"));
-                    result.push_str("ERROR_SEVERITY: MEDIUM\n");
-                    result.push_str("PROFILE_EXISTS: NOT_APPLICABLE\n");
-                    result.push_str("URL_FORMAT: VALID\n");
-                    result.push_str("DOMAIN_VERIFIED: TRUE\n");
-                    result.push_str("URL_TYPE: NON_PROFILE_LINKEDIN_URL\n");
-
-                    result.push_str("\nDETAILED_EXPLANATION:\n");
-                    result
-                        .push_str("The URL points to LinkedIn but is not a personal profile URL. ");
-                    result.push_str(
-                        "It might be a company page, job posting, or other LinkedIn content. ",
-                    );
-                    result.push_str(
-                        "Valid profile URLs follow the pattern: linkedin.com/in/username\n",
-                    );
-
-                    result.push_str("\nSUGGESTED_ACTIONS:\n");
-                    result.push_str(
-                        "1. Check if this is a company page URL (contains '/company/')\n",
-                    );
-                    result.push_str("2. Verify if this is a job posting URL (contains '/jobs/')\n");
-                    result.push_str(
-                        "3. Look for the '/in/' segment that indicates a personal profile\n",
-                    );
-                    result
-                        .push_str("4. Navigate to the person's actual profile page on LinkedIn\n");
-                    result.push_str("5. Use LinkedIn search to find the correct profile URL\n");
-
-                    result.push_str("\nRECOMMENDED_NEXT_STEP: Navigate to the personal profile section of LinkedIn\n");
-                }
-                LinkedInUrlError::ProfileNotFound => {
-                    result.push_str("ERROR_TYPE: PROFILE_NOT_FOUND\n");
-                    result.push_str(&format!("ERROR_MESSAGE: {e}\n"));
-                    result.push_str("ERROR_SEVERITY: LOW\n");
-                    result.push_str("PROFILE_EXISTS: FALSE\n");
-                    result.push_str("URL_FORMAT: VALID\n");
-                    result.push_str("DOMAIN_VERIFIED: TRUE\n");
-                    result.push_str("HTTP_STATUS: 404\n");
-
-                    result.push_str("\nDETAILED_EXPLANATION:\n");
-                    result.push_str("The URL format is correct and points to LinkedIn, but the profile does not exist. ");
-                    result
-                        .push_str("LinkedIn returned a 404 error or redirected to an error page. ");
-                    result.push_str("This means the username in the URL does not correspond to any active LinkedIn profile.\n");
-
-                    result.push_str("\nSUGGESTED_ACTIONS:\n");
-                    result.push_str("1. Double-check the username/URL for typos\n");
-                    result.push_str(
-                        "2. Verify if the profile might have been deleted or deactivated\n",
-                    );
-                    result.push_str("3. Check if the user might have changed their LinkedIn URL\n");
-                    result.push_str("4. Search for the person on LinkedIn using their name\n");
-                    result.push_str(
-                        "5. Contact the person to get their current LinkedIn profile URL\n",
-                    );
-                    result.push_str("6. Remove this URL from your database if it's stored\n");
-
-                    result.push_str("\nRECOMMENDED_NEXT_STEP: Search for the correct profile or mark as invalid in your system\n");
-                }
-                LinkedInUrlError::AuthenticationRequired => {
-                    result.push_str("ERROR_TYPE: AUTH_REQUIRED\n");
-                    result.push_str(&format!("ERROR_MESSAGE: {e}\n"));
-                    result.push_str("ERROR_SEVERITY: MEDIUM\n");
-                    result.push_str("PROFILE_EXISTS: UNKNOWN\n");
-                    result.push_str("URL_FORMAT: VALID\n");
-                    result.push_str("DOMAIN_VERIFIED: TRUE\n");
-                    result.push_str("LINKEDIN_RESPONSE: AUTHENTICATION_WALL\n");
-
-                    result.push_str("\nDETAILED_EXPLANATION:\n");
-                    result.push_str("LinkedIn is requiring authentication to view this profile. ");
-                    result.push_str("This typically happens when LinkedIn detects automated access patterns or when accessing from certain IP ranges. ");
-                    result.push_str("The profile format is valid and the profile LIKELY EXISTS, but LinkedIn is preventing automated verification.\n");
-
-                    result.push_str("\nSUGGESTED_ACTIONS:\n");
-                    result.push_str(
-                        "1. ACCEPT AS VALID: The URL format is correct and points to LinkedIn - treat as valid profile URL\n",
-                    );
-                    result.push_str(
-                        "2. RETRY LATER: Wait 30-60 seconds before trying to validate this URL again\n",
-                    );
-                    result.push_str("3. CONTINUE SEARCH: This URL is likely correct, but continue searching for alternative profiles if needed\n");
-                    result.push_str("4. CONFIDENCE: HIGH - LinkedIn auth walls usually indicate real profiles\n");
-                    result.push_str("5. FOR AI AGENTS: You can safely return this URL as a valid LinkedIn profile\n");
-                    result.push_str(
-                        "6. VERIFICATION: The URL follows pattern https://linkedin.com/in/USERNAME which is correct\n",
-                    );
-                    result.push_str(
-                        "7. RECOMMENDATION: Use this URL - it's almost certainly a valid profile\n",
-                    );
-
-                    result.push_str("\nRECOMMENDED_NEXT_STEP: ACCEPT this URL as valid - LinkedIn authentication requirements typically indicate the profile exists\n");
-                }
-                LinkedInUrlError::NetworkError(_) => {
-                    result.push_str("ERROR_TYPE: NETWORK_ERROR\n");
-                    result.push_str(&format!("ERROR_MESSAGE: {e}\n"));
-                    result.push_str("ERROR_SEVERITY: HIGH\n");
-                    result.push_str("PROFILE_EXISTS: UNKNOWN\n");
-                    result.push_str("URL_FORMAT: VALID\n");
-                    result.push_str("NETWORK_STATUS: FAILED\n");
-
-                    result.push_str("\nDETAILED_EXPLANATION:\n");
-                    result.push_str("Failed to establish a network connection to LinkedIn. ");
-                    result.push_str("This could be due to network connectivity issues, DNS resolution problems, ");
-                    result.push_str(
-                        "firewall restrictions, or LinkedIn being temporarily unavailable.\n",
-                    );
-
-                    result.push_str("\nSUGGESTED_ACTIONS:\n");
-                    result.push_str("1. Check internet connectivity with a simple ping test\n");
-                    result.push_str("2. Verify DNS resolution for linkedin.com\n");
-                    result.push_str("3. Check firewall settings for outbound HTTPS connections\n");
-                    result.push_str("4. Test if LinkedIn is accessible from a web browser\n");
-                    result.push_str("5. Implement retry logic with exponential backoff\n");
-                    result.push_str("6. Check for any proxy configuration requirements\n");
-                    result.push_str("7. Monitor LinkedIn's status page for any outages\n");
-
-                    result.push_str("\nRECOMMENDED_NEXT_STEP: Diagnose and resolve network connectivity issues\n");
-                }
-                LinkedInUrlError::ClientBuildError(ref msg) => {
-                    result.push_str("ERROR_TYPE: CLIENT_BUILD_ERROR\n");
-                    result.push_str(&format!("ERROR_MESSAGE: {e}\n"));
-                    result.push_str(&format!("ERROR_DETAILS: {msg}\n"));
-                    result.push_str("ERROR_SEVERITY: CRITICAL\n");
-                    result.push_str("PROFILE_EXISTS: UNKNOWN\n");
-
-                    result.push_str("\nDETAILED_EXPLANATION:\n");
-                    result.push_str("Failed to build the HTTP client needed for validation. ");
-                    result.push_str("This is an internal error that prevents any network requests from being made. ");
-                    result.push_str("Common causes include TLS configuration issues or system resource constraints.\n");
-
-                    result.push_str("\nSUGGESTED_ACTIONS:\n");
-                    result.push_str("1. Check system TLS/SSL library installation\n");
-                    result.push_str("2. Verify sufficient memory is available\n");
-                    result.push_str("3. Check for any security software blocking connections\n");
-                    result.push_str("4. Review system logs for detailed error information\n");
-                    result.push_str("5. Restart the application or service\n");
-                    result.push_str("6. Update system libraries and dependencies\n");
-
-                    result.push_str("\nRECOMMENDED_NEXT_STEP: Resolve system configuration issues before retry\n");
-                }
-            }
-        }
-    }
-
-    result.push_str("\n=== END OF VALIDATION REPORT ===\n");
-    result
+    report::llm_report(url, LinkedInValidator::new().and_then(|v| v.validate(url)))
 }
 
 /// Validates a LinkedIn profile URL asynchronously and returns a structured string for LLM consumption.
@@ -813,287 +1193,18 @@ pub fn validate_for_llm(url: &str) -> String {
 /// # }
 /// ```
 pub async fn validate_for_llm_async(url: &str) -> String {
-    let mut result = String::new();
-
-    // Header
-    result.push_str("=== LINKEDIN PROFILE VALIDATION REPORT ===\n\n");
-    result.push_str(&format!("TIMESTAMP: {}\n", chrono::Utc::now().to_rfc3339()));
-    result.push_str(&format!("INPUT_URL: {url}\n"));
-    result.push('\n');
-
-    match validate_linkedin_url_async(url).await {
-        Ok(_) => {
-            result.push_str("VALIDATION_RESULT: SUCCESS\n");
-            result.push_str("VALIDATION_STATUS: PASSED\n");
-            result.push_str("PROFILE_EXISTS: TRUE\n");
-            result.push_str("URL_FORMAT: VALID\n");
-            result.push_str("DOMAIN_VERIFIED: TRUE\n");
-            result.push_str("PROFILE_ACCESSIBLE: TRUE\n");
-
-            // Extract username from URL
-            if let Ok(parsed_url) = url::Url::parse(url) {
-                if let Some(mut path_segments) = parsed_url.path_segments() {
-                    if let Some(username) = path_segments.next_back() {
-                        result.push_str(&format!(
-                            "LINKEDIN_USERNAME: {}\n",
-                            username.trim_end_matches('/')
-                        ));
-                    }
-                }
-            }
-
-            result.push_str("\nDETAILED_EXPLANATION:\n");
-            result.push_str("The provided URL has been successfully validated. The LinkedIn profile exists and is accessible. ");
-            result.push_str("The URL follows the correct LinkedIn profile format and the domain has been verified as authentic. ");
-            result.push_str("The profile page returned a successful response, confirming the profile is active and publicly viewable.\n");
-
-            result.push_str("\nSUGGESTED_ACTIONS:\n");
-            result.push_str("1. Proceed with profile data extraction using LinkedIn API or web scraping tools\n");
-            result.push_str("2. Cache this validation result to avoid repeated network requests\n");
-            result.push_str(
-                "3. Store the profile URL in your database as a verified LinkedIn profile\n",
-            );
-            result.push_str(
-                "4. Consider extracting additional profile metadata (name, headline, etc.)\n",
-            );
-            result.push_str(
-                "5. Set up monitoring to periodically re-validate the profile existence\n",
-            );
-
-            result.push_str("\nRECOMMENDED_NEXT_STEP: Extract profile data using appropriate LinkedIn data extraction methods\n");
-        }
-        Err(e) => {
-            result.push_str("VALIDATION_RESULT: ERROR\n");
-            result.push_str("VALIDATION_STATUS: FAILED\n");
-
-            match e {
-                LinkedInUrlError::InvalidUrl(ref msg) => {
-                    result.push_str("ERROR_TYPE: INVALID_URL_FORMAT\n");
-                    result.push_str(&format!("ERROR_MESSAGE: {e}\n"));
-                    result.push_str(&format!("ERROR_DETAILS: {msg}\n"));
-                    result.push_str("ERROR_SEVERITY: HIGH\n");
-                    result.push_str("PROFILE_EXISTS: UNKNOWN\n");
-                    result.push_str("URL_FORMAT: INVALID\n");
-
-                    result.push_str("\nDETAILED_EXPLANATION:\n");
-                    result.push_str("The provided string is not a valid URL. The URL parser failed to interpret the input as a properly formatted URL. ");
-                    result.push_str("Common causes include missing protocol (http/https), invalid characters, or malformed structure.\n");
-
-                    result.push_str("\nSUGGESTED_ACTIONS:\n");
-                    result.push_str("1. Ensure the URL starts with 'https://' or 'http://'\n");
-                    result.push_str("2. Check for special characters that need URL encoding\n");
-                    result.push_str("3. Verify there are no spaces or line breaks in the URL\n");
-                    result.push_str(
-                        "4. Confirm the URL follows standard format: protocol://domain/path\n",
-                    );
-                    result.push_str(
-                        "5. Try URL encoding the input if it contains special characters\n",
-                    );
-                    result.push_str(
-                        "6. Example valid format: https://www.linkedin.com/in/username\n",
-                    );
-
-                    result.push_str(
-                        "\nRECOMMENDED_NEXT_STEP: Fix the URL format and retry validation\n",
-                    );
-                }
-                LinkedInUrlError::NotLinkedInUrl => {
-                    result.push_str("ERROR_TYPE: NOT_LINKEDIN_DOMAIN\n");
-                    result.push_str(&format!("ERROR_MESSAGE: {e}\n"));
-                    result.push_str("ERROR_SEVERITY: MEDIUM\n");
-                    result.push_str("PROFILE_EXISTS: NOT_APPLICABLE\n");
-                    result.push_str("URL_FORMAT: VALID\n");
-                    result.push_str("DOMAIN_VERIFIED: FALSE\n");
-
-                    // Extract the actual domain from URL
-                    if let Ok(parsed_url) = url::Url::parse(url) {
-                        if let Some(domain) = parsed_url.domain() {
-                            result.push_str(&format!("ACTUAL_DOMAIN: {domain}\n"));
-                        }
-                    }
-
-                    result.push_str("\nDETAILED_EXPLANATION:\n");
-                    result.push_str("The URL is valid but points to a domain other than LinkedIn. This validator specifically checks LinkedIn profile URLs. ");
-                    result.push_str("The domain must be either 'linkedin.com' or 'www.linkedin.com' to be considered valid.\n");
-
-                    result.push_str("\nSUGGESTED_ACTIONS:\n");
-                    result.push_str(
-                        "1. Replace the domain with 'linkedin.com' or 'www.linkedin.com'\n",
-                    );
-                    result.push_str(
-                        "2. Verify you have the correct URL from the LinkedIn platform\n",
-                    );
-                    result.push_str(
-                        "3. Check if the URL was copied correctly without modification\n",
-                    );
-                    result.push_str("4. If this is a different social media profile, use appropriate validators\n");
-                    result.push_str(
-                        "5. Ensure the URL is not from a LinkedIn clone or phishing site\n",
-                    );
-
-                    result.push_str("\nRECOMMENDED_NEXT_STEP: Obtain the correct LinkedIn profile URL from the official LinkedIn website\n");
-                }
-                LinkedInUrlError::NotProfileUrl => {
-                    result.push_str("ERROR_TYPE: NOT_PROFILE_URL\n");
-                    result.push_str(&format!("ERROR_MESSAGE: {e}\n"));
-                    result.push_str("ERROR_SEVERITY: MEDIUM\n");
-                    result.push_str("PROFILE_EXISTS: NOT_APPLICABLE\n");
-                    result.push_str("URL_FORMAT: VALID\n");
-                    result.push_str("DOMAIN_VERIFIED: TRUE\n");
-                    result.push_str("URL_TYPE: NON_PROFILE_LINKEDIN_URL\n");
-
-                    result.push_str("\nDETAILED_EXPLANATION:\n");
-                    result.push_str("The URL is from LinkedIn but does not point to a user profile. It may be a company page, job listing, or other LinkedIn content. ");
-                    result.push_str("This validator specifically checks for personal profile URLs that follow the pattern '/in/username'.\n");
-
-                    result.push_str("\nSUGGESTED_ACTIONS:\n");
-                    result.push_str("1. Navigate to the person's LinkedIn profile and copy the URL from there\n");
-                    result.push_str(
-                        "2. Look for URLs that contain '/in/' followed by the username\n",
-                    );
-                    result.push_str("3. If this is a company page, note that company validation requires different logic\n");
-                    result.push_str(
-                        "4. Check if you need to be logged in to LinkedIn to access the profile\n",
-                    );
-                    result.push_str(
-                        "5. Verify the profile URL format: https://linkedin.com/in/[username]\n",
-                    );
-
-                    result.push_str("\nRECOMMENDED_NEXT_STEP: Find the personal profile URL that includes '/in/' in the path\n");
-                }
-                LinkedInUrlError::ProfileNotFound => {
-                    result.push_str("ERROR_TYPE: PROFILE_NOT_FOUND\n");
-                    result.push_str(&format!("ERROR_MESSAGE: {e}\n"));
-                    result.push_str("ERROR_SEVERITY: HIGH\n");
-                    result.push_str("PROFILE_EXISTS: FALSE\n");
-                    result.push_str("URL_FORMAT: VALID\n");
-                    result.push_str("DOMAIN_VERIFIED: TRUE\n");
-                    result.push_str("HTTP_STATUS: 404\n");
-
-                    result.push_str("\nDETAILED_EXPLANATION:\n");
-                    result.push_str("The URL format is correct and points to LinkedIn, but the profile does not exist. ");
-                    result.push_str("This could mean the profile was deleted, the username was changed, or there's a typo in the username. ");
-                    result.push_str(
-                        "LinkedIn returned a 404 (Not Found) response for this profile URL.\n",
-                    );
-
-                    result.push_str("\nSUGGESTED_ACTIONS:\n");
-                    result.push_str(
-                        "1. Double-check the username for typos or case sensitivity issues\n",
-                    );
-                    result.push_str("2. Search for the person on LinkedIn using their name instead of direct URL\n");
-                    result.push_str("3. Ask the person for their current LinkedIn profile URL\n");
-                    result.push_str(
-                        "4. Check if the profile might have been recently deleted or deactivated\n",
-                    );
-                    result.push_str(
-                        "5. Try variations of the username (with/without hyphens, numbers, etc.)\n",
-                    );
-                    result.push_str(
-                        "6. Verify if the person has privacy settings that hide their profile\n",
-                    );
-                    result.push_str(
-                        "7. Consider that the profile might have moved to a different username\n",
-                    );
-
-                    result.push_str("\nRECOMMENDED_NEXT_STEP: Search for the person on LinkedIn by name or request their current profile URL\n");
-                }
-                LinkedInUrlError::AuthenticationRequired => {
-                    result.push_str("ERROR_TYPE: AUTH_REQUIRED\n");
-                    result.push_str(&format!("ERROR_MESSAGE: {e}\n"));
-                    result.push_str("ERROR_SEVERITY: MEDIUM\n");
-                    result.push_str("PROFILE_EXISTS: UNKNOWN\n");
-                    result.push_str("URL_FORMAT: VALID\n");
-                    result.push_str("DOMAIN_VERIFIED: TRUE\n");
-                    result.push_str("HTTP_STATUS: 999\n");
-                    result.push_str("LINKEDIN_RESPONSE: AUTHENTICATION_WALL\n");
-
-                    result.push_str("\nDETAILED_EXPLANATION:\n");
-                    result.push_str("LinkedIn is requiring authentication to verify this profile. This is a protective measure LinkedIn uses to prevent automated access. ");
-                    result.push_str("The HTTP 999 status code is LinkedIn's custom response for requests that appear to be automated. ");
-                    result.push_str("The profile format is valid and the profile LIKELY EXISTS, but LinkedIn is preventing automated verification.\n");
-
-                    result.push_str("\nSUGGESTED_ACTIONS:\n");
-                    result.push_str(
-                        "1. ACCEPT AS VALID: The URL format is correct and points to LinkedIn - treat as valid profile URL\n",
-                    );
-                    result.push_str(
-                        "2. RETRY LATER: Wait 30-60 seconds before trying to validate this URL again\n",
-                    );
-                    result.push_str("3. CONTINUE SEARCH: This URL is likely correct, but continue searching for alternative profiles if needed\n");
-                    result.push_str("4. CONFIDENCE: HIGH - LinkedIn auth walls usually indicate real profiles\n");
-                    result.push_str("5. FOR AI AGENTS: You can safely return this URL as a valid LinkedIn profile\n");
-                    result.push_str(
-                        "6. VERIFICATION: The URL follows pattern https://linkedin.com/in/USERNAME which is correct\n",
-                    );
-                    result.push_str(
-                        "7. RECOMMENDATION: Use this URL - it's almost certainly a valid profile\n",
-                    );
-
-                    result.push_str("\nRECOMMENDED_NEXT_STEP: ACCEPT this URL as valid - LinkedIn authentication requirements typically indicate the profile exists\n");
-                }
-                LinkedInUrlError::NetworkError(ref network_err) => {
-                    result.push_str("ERROR_TYPE: NETWORK_ERROR\n");
-                    result.push_str(&format!("ERROR_MESSAGE: {e}\n"));
-                    result.push_str(&format!("NETWORK_ERROR_DETAILS: {network_err}\n"));
-                    result.push_str("ERROR_SEVERITY: HIGH\n");
-                    result.push_str("PROFILE_EXISTS: UNKNOWN\n");
-                    result.push_str("NETWORK_STATUS: FAILED\n");
-
-                    result.push_str("\nDETAILED_EXPLANATION:\n");
-                    result.push_str("A network error occurred while trying to reach LinkedIn. This could be due to connectivity issues, ");
-                    result.push_str("DNS resolution problems, timeouts, or LinkedIn being temporarily unavailable. ");
-                    result.push_str("The validation could not be completed due to this network-level failure.\n");
-
-                    result.push_str("\nSUGGESTED_ACTIONS:\n");
-                    result.push_str("1. Check your internet connection and try again\n");
-                    result.push_str("2. Verify DNS resolution for linkedin.com is working\n");
-                    result
-                        .push_str("3. Check if LinkedIn is accessible from your network/region\n");
-                    result.push_str(
-                        "4. Retry the request after a short delay (exponential backoff)\n",
-                    );
-                    result
-                        .push_str("5. Check for firewall or proxy settings blocking the request\n");
-                    result.push_str("6. Verify SSL/TLS certificates are up to date\n");
-                    result.push_str(
-                        "7. Consider using a different network or VPN if the issue persists\n",
-                    );
-
-                    result.push_str("\nRECOMMENDED_NEXT_STEP: Diagnose network connectivity and retry the validation\n");
-                }
-                LinkedInUrlError::ClientBuildError(ref msg) => {
-                    result.push_str("ERROR_TYPE: CLIENT_BUILD_ERROR\n");
-                    result.push_str(&format!("ERROR_MESSAGE: {e}\n"));
-                    result.push_str(&format!("ERROR_DETAILS: {msg}\n"));
-                    result.push_str("ERROR_SEVERITY: CRITICAL\n");
-                    result.push_str("VALIDATION_STATUS: FAILED\n");
-
-                    result.push_str("\nDETAILED_EXPLANATION:\n");
-                    result.push_str("Failed to create the HTTP client needed for validation. This is typically caused by system resource constraints ");
-                    result.push_str("or TLS configuration issues. The validation cannot proceed without a working HTTP client.\n");
-
-                    result.push_str("\nSUGGESTED_ACTIONS:\n");
-                    result.push_str("1. Check available system memory and resources\n");
-                    result.push_str("2. Verify TLS/SSL libraries are properly installed\n");
-                    result.push_str("3. Check for system-level network restrictions\n");
-                    result.push_str("4. Restart the application or service\n");
-                    result.push_str("5. Review system logs for more detailed error information\n");
-
-                    result.push_str("\nRECOMMENDED_NEXT_STEP: Resolve system-level issues before attempting validation\n");
-                }
-            }
-        }
-    }
-
-    result.push_str("\n=== END OF VALIDATION REPORT ===\n");
-    result
+    let validation = match default_async_validator() {
+        Ok(validator) => validator.validate(url).await,
+        Err(e) => Err(e),
+    };
+    report::llm_report(url, validation)
 }
 
 /// Checks if a URL has valid `LinkedIn` profile format without making network calls.
 ///
 /// This function only validates the URL format and does not check if the profile exists.
-/// Use this for quick validation without network overhead.
+/// Use this for quick validation without network overhead. Legacy `/pub/` and
+/// `/profile/view?id=` profile URLs are accepted.
 ///
 /// # Arguments
 ///
@@ -1110,16 +1221,13 @@ pub async fn validate_for_llm_async(url: &str) -> String {
 /// use credify::is_valid_linkedin_profile_format;
 ///
 /// assert!(is_valid_linkedin_profile_format("https://www.linkedin.com/in/johndoe"));
+/// assert!(is_valid_linkedin_profile_format("https://www.linkedin.com/pub/jane-doe/12/345/678"));
 /// assert!(!is_valid_linkedin_profile_format("https://www.google.com/in/johndoe"));
 /// assert!(!is_valid_linkedin_profile_format("https://linkedin.com/company/microsoft"));
 /// ```
 #[must_use]
 pub fn is_valid_linkedin_profile_format(url: &str) -> bool {
-    let Ok(url_parsed) = Url::parse(url) else {
-        return false;
-    };
-
-    is_linkedin_domain(&url_parsed) && is_profile_path(&url_parsed)
+    parse_profile_url(url, &ValidationSettings::new()).is_ok()
 }
 
 #[cfg(test)]
@@ -1144,6 +1252,21 @@ mod tests {
         assert!(is_valid_linkedin_profile_format(
             "https://www.linkedin.com/in/john-doe-123/"
         ));
+        assert!(is_valid_linkedin_profile_format(
+            "https://uk.linkedin.com/in/johndoe"
+        ));
+        assert!(is_valid_linkedin_profile_format(
+            "https://M.LinkedIn.com/in/johndoe"
+        ));
+        assert!(is_valid_linkedin_profile_format(
+            "https://www.linkedin.com/in/johndoe/en"
+        ));
+        assert!(is_valid_linkedin_profile_format(
+            "https://www.linkedin.com/in/johndoe/details/experience/"
+        ));
+        assert!(is_valid_linkedin_profile_format(
+            "https://www.linkedin.com/in/johndoe/?originalSubdomain=uk"
+        ));
     }
 
     #[test]
@@ -1155,6 +1278,9 @@ mod tests {
             "https://linkedin.com/company/microsoft"
         ));
         assert!(!is_valid_linkedin_profile_format("https://linkedin.com/"));
+        assert!(!is_valid_linkedin_profile_format(
+            "https://linkedin.com.evil.io/in/johndoe"
+        ));
         assert!(!is_valid_linkedin_profile_format("not-a-url"));
     }
 
@@ -1289,7 +1415,13 @@ mod tests {
         assert!(result.contains("ERROR_TYPE: NOT_PROFILE_URL"));
         assert!(result.contains("SUGGESTED_ACTIONS:"));
         assert!(result.contains("URL_TYPE: NON_PROFILE_LINKEDIN_URL"));
+        assert!(result.contains("LINKEDIN_ENTITY: COMPANY_PAGE"));
+        assert!(result.contains("ENTITY_IDENTIFIER: microsoft"));
         assert!(result.contains("RECOMMENDED_NEXT_STEP:"));
+
+        let result = ai_validate("https://www.linkedin.com/jobs/view/3812345678/");
+        assert_eq!(result.metadata.error_type.as_deref(), Some("NOT_PROFILE"));
+        assert!(result.reason.contains("job posting '3812345678'"));
     }
 
     #[tokio::test]
@@ -1361,9 +1493,18 @@ pub struct AIValidationResult {
     /// Extracted username if available
     pub username: Option<String>,
 
+    /// Canonical `https://www.linkedin.com/in/<username>` form of the URL, if it is a profile URL
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub canonical_url: Option<String>,
+
     /// Human-readable reason
     pub reason: String,
 
+    /// Name, headline, location and photo read from the profile page, when profile
+    /// summaries are fetched and the page was served
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile_summary: Option<PublicProfileSummary>,
+
     /// Detailed metadata
     pub metadata: ValidationMetadata,
 }
@@ -1380,7 +1521,9 @@ pub enum AIDecision {
 }
 
 /// Validation metadata for advanced AI agents
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// The default has every check failed and every optional field unset.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ValidationMetadata {
     pub url_format_valid: bool,
     pub domain_verified: bool,
@@ -1388,6 +1531,27 @@ pub struct ValidationMetadata {
     pub http_status: Option<u16>,
     pub error_type: Option<String>,
     pub timestamp: String,
+    /// Which `LinkedIn` host the URL pointed at, once the domain was verified
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub host_variant: Option<HostVariant>,
+    /// The short link that was given, when it was resolved before validation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_url: Option<String>,
+    /// The URL the short link resolved to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved_url: Option<String>,
+    /// The repaired URL that was validated in place of malformed input, with the fixes applied
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repair: Option<RepairedUrl>,
+    /// How many times the profile page was requested, including retries
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attempts: Option<u32>,
+    /// Whether the result was served from the validator's cache
+    #[serde(default)]
+    pub cached: bool,
+    /// When a cached result was stored
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cached_at: Option<String>,
 }
 
 /// Validate LinkedIn URL optimized for AI agents (sync version)
@@ -1406,373 +1570,197 @@ pub struct ValidationMetadata {
 /// }
 /// ```
 pub fn ai_validate(url: &str) -> AIValidationResult {
-    let timestamp = chrono::Utc::now().to_rfc3339();
-
-    // First check URL format
-    let parsed_url = match Url::parse(url) {
-        Ok(u) => u,
-        Err(e) => {
-            return AIValidationResult {
-                is_valid: false,
-                confidence: 1.0,
-                decision: AIDecision::Reject,
-                username: None,
-                reason: format!("Invalid URL format: {e}"),
-                metadata: ValidationMetadata {
-                    url_format_valid: false,
-                    domain_verified: false,
-                    profile_pattern_matched: false,
-                    http_status: None,
-                    error_type: Some("INVALID_URL".to_string()),
-                    timestamp,
-                },
-            };
-        }
-    };
-
-    // Check domain
-    let domain_valid = is_linkedin_domain(&parsed_url);
-    if !domain_valid {
-        return AIValidationResult {
-            is_valid: false,
-            confidence: 1.0,
-            decision: AIDecision::Reject,
-            username: None,
-            reason: "Not a LinkedIn URL".to_string(),
-            metadata: ValidationMetadata {
-                url_format_valid: true,
-                domain_verified: false,
-                profile_pattern_matched: false,
-                http_status: None,
-                error_type: Some("WRONG_DOMAIN".to_string()),
-                timestamp,
-            },
-        };
+    match LinkedInValidator::new() {
+        Ok(validator) => validator.ai_validate(url),
+        Err(e) => report::ai_result(url, &ValidationSettings::new(), || Err(e)),
     }
+}
 
-    // Check profile pattern
-    let is_profile = is_profile_path(&parsed_url);
-    if !is_profile {
-        return AIValidationResult {
-            is_valid: false,
-            confidence: 0.95,
-            decision: AIDecision::Reject,
-            username: None,
-            reason: "LinkedIn URL but not a profile (might be company page)".to_string(),
-            metadata: ValidationMetadata {
-                url_format_valid: true,
-                domain_verified: true,
-                profile_pattern_matched: false,
-                http_status: None,
-                error_type: Some("NOT_PROFILE".to_string()),
-                timestamp,
-            },
-        };
+/// Async version of ai_validate
+pub async fn ai_validate_async(url: &str) -> AIValidationResult {
+    match default_async_validator() {
+        Ok(validator) => validator.ai_validate(url).await,
+        Err(e) => report::ai_result_async(url, &ValidationSettings::new(), async { Err(e) }).await,
     }
+}
 
-    // Extract username
-    let username = parsed_url
-        .path_segments()
-        .and_then(|mut segments| {
-            // Skip to "in" then get next segment
-            if segments.next() == Some("in") {
-                segments.next()
-            } else {
-                None
-            }
-        })
-        .filter(|u| !u.is_empty())
-        .map(|u| u.trim_end_matches('/').to_string());
-
-    // Try to validate with HTTP request
-    let validator = match LinkedInValidator::new() {
-        Ok(v) => v,
-        Err(_) => {
-            // Can't create validator, but URL format is good
-            return AIValidationResult {
-                is_valid: true,
-                confidence: 0.7,
-                decision: AIDecision::Accept,
-                username,
-                reason: "URL format is valid (network check unavailable)".to_string(),
-                metadata: ValidationMetadata {
-                    url_format_valid: true,
-                    domain_verified: true,
-                    profile_pattern_matched: true,
-                    http_status: None,
-                    error_type: Some("VALIDATOR_ERROR".to_string()),
-                    timestamp,
-                },
-            };
-        }
+/// Validates a URL and scores whether the profile belongs to the person described by
+/// `expected`.
+///
+/// The profile summary is fetched, so the vanity name, display name, headline and location
+/// all count; see [`score_identity`] for the signals.
+///
+/// # Example
+///
+/// ```no_run
+/// use credify::{MatchVerdict, PersonHint, match_profile};
+///
+/// let hint = PersonHint::new("Jane Smith").company("Acme");
+/// let identity = match_profile(&hint, "https://www.linkedin.com/in/jsmith-42");
+/// if identity.verdict == MatchVerdict::Likely {
+///     println!("Found Jane with score {:.2}", identity.score);
+/// }
+/// for signal in &identity.signals {
+///     println!("{}: {}", signal.kind, signal.explanation);
+/// }
+/// ```
+pub fn match_profile(expected: &PersonHint, url: &str) -> IdentityMatch {
+    let validation = match LinkedInValidator::new() {
+        Ok(validator) => validator.with_profile_summary(true).ai_validate(url),
+        Err(e) => report::ai_result(url, &ValidationSettings::new(), || Err(e)),
     };
-
-    // Perform actual validation
-    match validator.is_valid_linkedin_profile_url(url) {
-        Ok(_) => AIValidationResult {
-            is_valid: true,
-            confidence: 1.0,
-            decision: AIDecision::Accept,
-            username,
-            reason: "Verified LinkedIn profile exists".to_string(),
-            metadata: ValidationMetadata {
-                url_format_valid: true,
-                domain_verified: true,
-                profile_pattern_matched: true,
-                http_status: Some(200),
-                error_type: None,
-                timestamp,
-            },
-        },
-        Err(LinkedInUrlError::AuthenticationRequired) => {
-            // This is actually a GOOD sign - LinkedIn auth walls mean real profiles
-            AIValidationResult {
-                is_valid: true,
-                confidence: 0.9,
-                decision: AIDecision::Accept,
-                username,
-                reason: "LinkedIn profile likely exists (auth required)".to_string(),
-                metadata: ValidationMetadata {
-                    url_format_valid: true,
-                    domain_verified: true,
-                    profile_pattern_matched: true,
-                    http_status: Some(999),
-                    error_type: Some("AUTH_REQUIRED".to_string()),
-                    timestamp,
-                },
-            }
-        }
-        Err(LinkedInUrlError::ProfileNotFound) => AIValidationResult {
-            is_valid: false,
-            confidence: 0.95,
-            decision: AIDecision::Reject,
-            username,
-            reason: "LinkedIn profile does not exist (404)".to_string(),
-            metadata: ValidationMetadata {
-                url_format_valid: true,
-                domain_verified: true,
-                profile_pattern_matched: true,
-                http_status: Some(404),
-                error_type: Some("NOT_FOUND".to_string()),
-                timestamp,
-            },
-        },
-        Err(LinkedInUrlError::NetworkError(_)) => AIValidationResult {
-            is_valid: true,
-            confidence: 0.6,
-            decision: AIDecision::Retry,
-            username,
-            reason: "Network error - retry later".to_string(),
-            metadata: ValidationMetadata {
-                url_format_valid: true,
-                domain_verified: true,
-                profile_pattern_matched: true,
-                http_status: None,
-                error_type: Some("NETWORK_ERROR".to_string()),
-                timestamp,
-            },
-        },
-        Err(e) => AIValidationResult {
-            is_valid: false,
-            confidence: 0.2,
-            decision: AIDecision::Reject,
-            username,
-            reason: format!("Validation error: {e}"),
-            metadata: ValidationMetadata {
-                url_format_valid: true,
-                domain_verified: true,
-                profile_pattern_matched: true,
-                http_status: None,
-                error_type: Some("OTHER_ERROR".to_string()),
-                timestamp,
-            },
-        },
-    }
+    score_identity(expected, validation)
 }
 
-/// Async version of ai_validate
-pub async fn ai_validate_async(url: &str) -> AIValidationResult {
-    let timestamp = chrono::Utc::now().to_rfc3339();
-
-    // First check URL format
-    let parsed_url = match Url::parse(url) {
-        Ok(u) => u,
-        Err(e) => {
-            return AIValidationResult {
-                is_valid: false,
-                confidence: 1.0,
-                decision: AIDecision::Reject,
-                username: None,
-                reason: format!("Invalid URL format: {e}"),
-                metadata: ValidationMetadata {
-                    url_format_valid: false,
-                    domain_verified: false,
-                    profile_pattern_matched: false,
-                    http_status: None,
-                    error_type: Some("INVALID_URL".to_string()),
-                    timestamp,
-                },
-            };
-        }
+/// Async version of [`match_profile`]
+pub async fn match_profile_async(expected: &PersonHint, url: &str) -> IdentityMatch {
+    let validation = match default_async_validator() {
+        Ok(validator) => validator.with_profile_summary(true).ai_validate(url).await,
+        Err(e) => report::ai_result_async(url, &ValidationSettings::new(), async { Err(e) }).await,
     };
+    score_identity(expected, validation)
+}
 
-    // Check domain
-    let domain_valid = is_linkedin_domain(&parsed_url);
-    if !domain_valid {
-        return AIValidationResult {
-            is_valid: false,
-            confidence: 1.0,
-            decision: AIDecision::Reject,
-            username: None,
-            reason: "Not a LinkedIn URL".to_string(),
-            metadata: ValidationMetadata {
-                url_format_valid: true,
-                domain_verified: false,
-                profile_pattern_matched: false,
-                http_status: None,
-                error_type: Some("WRONG_DOMAIN".to_string()),
-                timestamp,
-            },
-        };
+/// Generates the profile URLs a person is likely to have, most likely first.
+///
+/// Vanity names are built from the name with and without hyphens, with the first or middle
+/// initial, in reverse order and with numeric suffixes such as `jane-smith-2`. Accents are
+/// stripped, and names with accents also get `first-last` and `firstlast` candidates that
+/// keep them, such as `josé-müller`. Use this instead of guessing a URL when a profile is
+/// not found. Only the candidates within the
+/// [validation budget](SuggestOptions::validation_budget) are validated, so by default no
+/// request is made; check [`ProfileSuggestion::exists`] for the result.
+///
+/// # Example
+///
+/// ```
+/// use credify::{SuggestOptions, suggest_profile_urls};
+///
+/// let suggestions = suggest_profile_urls("Jane", "Smith", &SuggestOptions::new());
+/// assert_eq!(suggestions[0].url, "https://www.linkedin.com/in/jane-smith");
+/// assert_eq!(suggestions[2].username, "jsmith");
+/// ```
+///
+/// Validating the three most likely candidates:
+///
+/// ```no_run
+/// use credify::{SuggestOptions, suggest_profile_urls};
+///
+/// let options = SuggestOptions::new().validation_budget(3);
+/// for suggestion in suggest_profile_urls("Jane", "Smith", &options) {
+///     if suggestion.exists() == Some(true) {
+///         println!("Found {}", suggestion.url);
+///     }
+/// }
+/// ```
+pub fn suggest_profile_urls(
+    first: &str,
+    last: &str,
+    options: &SuggestOptions,
+) -> Vec<ProfileSuggestion> {
+    if options.max_validations() == 0 {
+        return suggest::candidates(first, last, options);
+    }
+    match LinkedInValidator::new() {
+        Ok(validator) => validator.suggest_profile_urls(first, last, options),
+        Err(e) => unchecked_suggestions(first, last, options, &e),
     }
+}
 
-    // Check profile pattern
-    let is_profile = is_profile_path(&parsed_url);
-    if !is_profile {
-        return AIValidationResult {
-            is_valid: false,
-            confidence: 0.95,
-            decision: AIDecision::Reject,
-            username: None,
-            reason: "LinkedIn URL but not a profile (might be company page)".to_string(),
-            metadata: ValidationMetadata {
-                url_format_valid: true,
-                domain_verified: true,
-                profile_pattern_matched: false,
-                http_status: None,
-                error_type: Some("NOT_PROFILE".to_string()),
-                timestamp,
-            },
-        };
+/// Async version of [`suggest_profile_urls`]
+pub async fn suggest_profile_urls_async(
+    first: &str,
+    last: &str,
+    options: &SuggestOptions,
+) -> Vec<ProfileSuggestion> {
+    if options.max_validations() == 0 {
+        return suggest::candidates(first, last, options);
+    }
+    match default_async_validator() {
+        Ok(validator) => validator.suggest_profile_urls(first, last, options).await,
+        Err(e) => unchecked_suggestions(first, last, options, &e),
     }
+}
 
-    // Extract username
-    let username = parsed_url
-        .path_segments()
-        .and_then(|mut segments| {
-            // Skip to "in" then get next segment
-            if segments.next() == Some("in") {
-                segments.next()
-            } else {
-                None
-            }
-        })
-        .filter(|u| !u.is_empty())
-        .map(|u| u.trim_end_matches('/').to_string());
-
-    // Perform actual validation
-    match validate_linkedin_url_async(url).await {
-        Ok(_) => AIValidationResult {
-            is_valid: true,
-            confidence: 1.0,
-            decision: AIDecision::Accept,
-            username,
-            reason: "Verified LinkedIn profile exists".to_string(),
-            metadata: ValidationMetadata {
-                url_format_valid: true,
-                domain_verified: true,
-                profile_pattern_matched: true,
-                http_status: Some(200),
-                error_type: None,
-                timestamp,
-            },
-        },
-        Err(LinkedInUrlError::AuthenticationRequired) => {
-            // This is actually a GOOD sign - LinkedIn auth walls mean real profiles
-            AIValidationResult {
-                is_valid: true,
-                confidence: 0.9,
-                decision: AIDecision::Accept,
-                username,
-                reason: "LinkedIn profile likely exists (auth required)".to_string(),
-                metadata: ValidationMetadata {
-                    url_format_valid: true,
-                    domain_verified: true,
-                    profile_pattern_matched: true,
-                    http_status: Some(999),
-                    error_type: Some("AUTH_REQUIRED".to_string()),
-                    timestamp,
-                },
-            }
-        }
-        Err(LinkedInUrlError::ProfileNotFound) => AIValidationResult {
-            is_valid: false,
-            confidence: 0.95,
-            decision: AIDecision::Reject,
-            username,
-            reason: "LinkedIn profile does not exist (404)".to_string(),
-            metadata: ValidationMetadata {
-                url_format_valid: true,
-                domain_verified: true,
-                profile_pattern_matched: true,
-                http_status: Some(404),
-                error_type: Some("NOT_FOUND".to_string()),
-                timestamp,
-            },
-        },
-        Err(LinkedInUrlError::NetworkError(_)) => AIValidationResult {
-            is_valid: true,
-            confidence: 0.6,
-            decision: AIDecision::Retry,
-            username,
-            reason: "Network error - retry later".to_string(),
-            metadata: ValidationMetadata {
-                url_format_valid: true,
-                domain_verified: true,
-                profile_pattern_matched: true,
-                http_status: None,
-                error_type: Some("NETWORK_ERROR".to_string()),
-                timestamp,
-            },
-        },
-        Err(e) => AIValidationResult {
-            is_valid: false,
-            confidence: 0.2,
-            decision: AIDecision::Reject,
-            username,
-            reason: format!("Validation error: {e}"),
-            metadata: ValidationMetadata {
-                url_format_valid: true,
-                domain_verified: true,
-                profile_pattern_matched: true,
-                http_status: None,
-                error_type: Some("OTHER_ERROR".to_string()),
-                timestamp,
-            },
-        },
+/// Reports the candidates within the budget as unchecked when no validator can be built.
+fn unchecked_suggestions(
+    first: &str,
+    last: &str,
+    options: &SuggestOptions,
+    error: &LinkedInUrlError,
+) -> Vec<ProfileSuggestion> {
+    let mut suggestions = suggest::candidates(first, last, options);
+    for suggestion in suggestions.iter_mut().take(options.max_validations()) {
+        suggestion.validation = Some(report::ai_result(
+            &suggestion.url,
+            &ValidationSettings::new(),
+            || Err(LinkedInUrlError::ClientBuildError(error.to_string())),
+        ));
     }
+    suggestions
+}
+
+/// Validates many URLs concurrently with the shared async validator, yielding
+/// `(input, result)` pairs as they complete.
+///
+/// Inputs with the same canonical profile URL are validated once, within the last
+/// [`BatchOptions::remembered_results`] finished results. Concurrency and the token-bucket
+/// rate limit come from `options`.
+///
+/// # Example
+///
+/// ```no_run
+/// use credify::{BatchOptions, validate_stream};
+/// use futures_util::StreamExt;
+///
+/// # async fn example(urls: Vec<String>) {
+/// let options = BatchOptions::new().concurrency(16).rate_limit(10.0, 10);
+/// let mut results = std::pin::pin!(validate_stream(futures_util::stream::iter(urls), options));
+/// while let Some((url, result)) = results.next().await {
+///     println!("{url}: {}", result.reason);
+/// }
+/// # }
+/// ```
+pub fn validate_stream<S>(
+    urls: S,
+    options: BatchOptions,
+) -> impl futures_util::Stream<Item = (String, AIValidationResult)>
+where
+    S: futures_util::Stream,
+    S::Item: Into<String>,
+{
+    use futures_util::StreamExt;
+
+    batch::ai_result_stream(urls.map(Into::into), options, |url| async move {
+        ai_validate_async(&url).await
+    })
+}
+
+/// Validates many URLs concurrently with the shared async validator and returns
+/// `(input, result)` pairs in completion order.
+///
+/// Every finished result is kept for the duplicates that follow. See [`validate_stream`].
+pub async fn validate_batch<I>(urls: I, options: BatchOptions) -> Vec<(String, AIValidationResult)>
+where
+    I: IntoIterator,
+    I::Item: Into<String>,
+{
+    use futures_util::StreamExt;
+
+    validate_stream(
+        futures_util::stream::iter(urls),
+        options.remember_results(usize::MAX),
+    )
+    .collect()
+    .await
 }
 
 /// Get validation result as JSON for AI agents
 pub fn ai_validate_json(url: &str) -> String {
-    let result = ai_validate(url);
-    serde_json::to_string_pretty(&result).unwrap_or_else(|_| {
-        json!({
-            "error": "Failed to serialize result",
-            "is_valid": false
-        })
-        .to_string()
-    })
+    report::ai_json(&ai_validate(url))
 }
 
 /// Async version of ai_validate_json
 pub async fn ai_validate_json_async(url: &str) -> String {
-    let result = ai_validate_async(url).await;
-    serde_json::to_string_pretty(&result).unwrap_or_else(|_| {
-        json!({
-            "error": "Failed to serialize result",
-            "is_valid": false
-        })
-        .to_string()
-    })
+    report::ai_json(&ai_validate_async(url).await)
 }
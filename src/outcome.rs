@@ -0,0 +1,205 @@
+//! Structured result of checking a `LinkedIn` profile or company page.
+
+use crate::company_url::CompanyPath;
+use crate::profile_url::{ProfileTarget, resolve_redirect};
+use crate::retry::Attempts;
+use crate::settings::ValidationSettings;
+use crate::{
+    DetectionRules, EntityKind, HostVariant, HttpResponse, LegacyFormat, ProfileSection,
+    ProfileStatus, PublicProfileSummary, ShortLink, parse_profile_summary,
+};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Everything a validator learned about a profile URL.
+///
+/// Returned by [`LinkedInValidator::validate`](crate::LinkedInValidator::validate) and
+/// [`AsyncLinkedInValidator::validate`](crate::AsyncLinkedInValidator::validate). Unlike
+/// `is_valid_linkedin_profile_url`, a profile that is missing, hidden or rate limited is
+/// reported through [`status`](Self::status) rather than as an error, so callers can
+/// match every case exhaustively. Sub-page URLs such as `/in/<username>/details/experience/`
+/// are checked against the root profile.
+///
+/// [`LinkedInValidator::validate_company_url`](crate::LinkedInValidator::validate_company_url)
+/// returns the same structure for company pages, with [`entity`](Self::entity) set to
+/// [`EntityKind::Company`] and the company slug as [`username`](Self::username).
+///
+/// # Example
+///
+/// ```
+/// use credify::{HostVariant, HttpResponse, LinkedInValidator, ProfileStatus, ScriptedTransport};
+///
+/// let transport = ScriptedTransport::new();
+/// transport.enqueue(HttpResponse::new(200, "https://www.linkedin.com/in/johndoe"));
+///
+/// let validator = LinkedInValidator::with_transport(transport);
+/// let outcome = validator.validate("https://linkedin.com/in/johndoe/").unwrap();
+///
+/// assert_eq!(outcome.status, ProfileStatus::Exists);
+/// assert_eq!(outcome.username, "johndoe");
+/// assert_eq!(outcome.canonical_url, "https://www.linkedin.com/in/johndoe");
+/// assert_eq!(outcome.host_variant, HostVariant::Apex);
+/// assert_eq!(outcome.http_status, 200);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationOutcome {
+    /// What kind of page was checked
+    #[serde(default)]
+    pub entity: EntityKind,
+    /// What `LinkedIn` said about the profile
+    pub status: ProfileStatus,
+    /// The profile URL in its canonical `https://www.linkedin.com/in/<username>` form
+    ///
+    /// For a legacy URL this is the `/in/` profile it redirected to, or the legacy URL
+    /// itself when `LinkedIn` did not redirect to a profile.
+    pub canonical_url: String,
+    /// The profile's public identifier, or the name or id of an unresolved legacy URL
+    ///
+    /// For company pages this is the company slug or numeric id.
+    pub username: String,
+    /// Which `LinkedIn` host the input URL pointed at
+    pub host_variant: HostVariant,
+    /// Which part of the profile the input URL pointed at
+    pub section: ProfileSection,
+    /// HTTP status code of the final response
+    pub http_status: u16,
+    /// URL of the final response after redirects
+    pub final_url: String,
+    /// Wall-clock time the check took: the requests, including short link hops and the 999
+    /// retry, and any throttling backoff, circuit breaker cooldown or retry wait
+    pub elapsed: Duration,
+    /// How many times the page was requested, including the 999 cookie retry and retries
+    #[serde(default)]
+    pub attempts: u32,
+    /// The legacy format of the input URL, if it was not an `/in/` URL
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub legacy_format: Option<LegacyFormat>,
+    /// The `lnkd.in` short link the input URL was, and where it led
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub short_link: Option<ShortLink>,
+    /// What the served profile page tells about the person, when profile summaries are
+    /// fetched
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<PublicProfileSummary>,
+}
+
+impl ValidationOutcome {
+    /// Builds the outcome for `profile` from the final response.
+    ///
+    /// A legacy URL is resolved to the profile `LinkedIn` redirected it to.
+    pub(crate) fn from_response(
+        profile: ProfileTarget,
+        host_variant: HostVariant,
+        response: &HttpResponse,
+        rules: &DetectionRules,
+        elapsed: Duration,
+    ) -> Self {
+        let (resolved, legacy_format) = match profile {
+            ProfileTarget::Modern(profile) => (Ok(profile), None),
+            ProfileTarget::Legacy(legacy) => {
+                let format = legacy.format;
+                (
+                    resolve_redirect(&response.final_url, response.header("location"))
+                        .ok_or(legacy),
+                    Some(format),
+                )
+            }
+        };
+        let (canonical_url, username, section) = match resolved {
+            Ok(profile) => (
+                profile.canonical.as_str().to_string(),
+                profile.canonical.username().to_string(),
+                profile.section,
+            ),
+            Err(legacy) => (legacy.url, legacy.identifier, ProfileSection::Main),
+        };
+
+        Self {
+            legacy_format,
+            section,
+            ..Self::new(
+                EntityKind::Person,
+                canonical_url,
+                username,
+                host_variant,
+                response,
+                rules,
+                elapsed,
+            )
+        }
+    }
+
+    /// Builds the outcome for a company page from the final response.
+    pub(crate) fn from_company_response(
+        company: CompanyPath,
+        host_variant: HostVariant,
+        response: &HttpResponse,
+        rules: &DetectionRules,
+        elapsed: Duration,
+    ) -> Self {
+        Self::new(
+            EntityKind::Company,
+            company.canonical,
+            company.slug,
+            host_variant,
+            response,
+            rules,
+            elapsed,
+        )
+    }
+
+    fn new(
+        entity: EntityKind,
+        canonical_url: String,
+        username: String,
+        host_variant: HostVariant,
+        response: &HttpResponse,
+        rules: &DetectionRules,
+        elapsed: Duration,
+    ) -> Self {
+        Self {
+            entity,
+            status: rules.classify(
+                response.status,
+                &response.final_url,
+                &response.headers,
+                &response.body,
+            ),
+            canonical_url,
+            username,
+            host_variant,
+            section: ProfileSection::Main,
+            http_status: response.status,
+            final_url: response.final_url.clone(),
+            elapsed,
+            attempts: 0,
+            legacy_format: None,
+            short_link: None,
+            summary: None,
+        }
+    }
+
+    /// Records how many page requests were made.
+    pub(crate) const fn with_attempts(mut self, attempts: &Attempts) -> Self {
+        self.attempts = attempts.count;
+        self
+    }
+
+    /// Records the short link the profile URL was resolved from.
+    pub(crate) fn with_short_link(mut self, short_link: Option<ShortLink>) -> Self {
+        self.short_link = short_link;
+        self
+    }
+
+    /// Reads the public profile summary from the served page if `settings` ask for it.
+    pub(crate) fn with_summary(
+        mut self,
+        response: &HttpResponse,
+        settings: &ValidationSettings,
+    ) -> Self {
+        if settings.fetch_profile_summary && self.status == ProfileStatus::Exists {
+            self.summary = parse_profile_summary(&response.body);
+        }
+        self
+    }
+}
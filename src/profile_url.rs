@@ -0,0 +1,550 @@
+//! Canonical form of `LinkedIn` profile URLs.
+
+use crate::{DomainPolicy, LinkedInUrlError};
+use percent_encoding::percent_decode_str;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use thiserror::Error;
+use url::Url;
+
+/// Host used by every canonical profile URL.
+pub(crate) const CANONICAL_PREFIX: &str = "https://www.linkedin.com/in/";
+
+/// Shortest username `LinkedIn` accepts, in characters.
+const MIN_USERNAME_CHARS: usize = 3;
+
+/// Longest username `LinkedIn` accepts, in characters.
+const MAX_USERNAME_CHARS: usize = 100;
+
+/// The `LinkedIn` username rule a profile URL breaks.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum UsernameRule {
+    /// The username has fewer than 3 characters.
+    #[error("usernames need at least {min} characters, got {0}", min = MIN_USERNAME_CHARS)]
+    TooShort(usize),
+
+    /// The username has more than 100 characters.
+    #[error("usernames can have at most {max} characters, got {0}", max = MAX_USERNAME_CHARS)]
+    TooLong(usize),
+
+    /// The username contains a character other than letters, digits, '-' and '.'.
+    #[error("usernames can only contain letters, digits, '-' and '.', found {0:?}")]
+    InvalidCharacter(char),
+
+    /// The percent-encoded username does not decode to UTF-8.
+    #[error("the username is not valid percent-encoded UTF-8")]
+    InvalidEncoding,
+}
+
+/// A `LinkedIn` profile URL in canonical form.
+///
+/// The canonical form is `https://www.linkedin.com/in/<username>` where the username is
+/// percent-decoded and lowercased (non-ASCII letters are kept as-is, not re-encoded), and
+/// query strings, fragments, trailing slashes and locale suffixes are dropped. Two URLs
+/// pointing at the same profile have equal canonical forms.
+///
+/// # Example
+///
+/// ```
+/// use credify::normalize_profile_url;
+///
+/// let a = normalize_profile_url("http://linkedin.com/in/Jane-Doe").unwrap();
+/// let b = normalize_profile_url("https://www.linkedin.com/in/jane-doe/?originalSubdomain=uk").unwrap();
+/// let c = normalize_profile_url("https://de.linkedin.com/in/jane-doe/en").unwrap();
+///
+/// assert_eq!(a, b);
+/// assert_eq!(b, c);
+/// assert_eq!(a.as_str(), "https://www.linkedin.com/in/jane-doe");
+/// assert_eq!(a.username(), "jane-doe");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CanonicalProfileUrl {
+    url: String,
+}
+
+impl CanonicalProfileUrl {
+    fn from_username(username: &str) -> Self {
+        Self {
+            url: format!("{CANONICAL_PREFIX}{username}"),
+        }
+    }
+
+    /// Returns the canonical URL.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.url
+    }
+
+    /// Returns the normalized username (vanity name).
+    #[must_use]
+    pub fn username(&self) -> &str {
+        &self.url[CANONICAL_PREFIX.len()..]
+    }
+
+    /// Consumes the value and returns the canonical URL.
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.url
+    }
+}
+
+impl fmt::Display for CanonicalProfileUrl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.url)
+    }
+}
+
+impl AsRef<str> for CanonicalProfileUrl {
+    fn as_ref(&self) -> &str {
+        &self.url
+    }
+}
+
+/// Which part of a profile a URL points at.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ProfileSection {
+    /// The profile itself, possibly with a locale suffix such as `/en`
+    Main,
+    /// A `/details/<section>/` page such as `experience` or `skills`
+    Details(String),
+    /// The `/recent-activity/` feed, optionally filtered (`all`, `comments`, ...)
+    RecentActivity(Option<String>),
+    /// The `/overlay/contact-info/` dialog
+    ContactInfo,
+    /// Another `/overlay/<name>/` dialog
+    Overlay(String),
+}
+
+/// Pre-2014 profile URL shapes that `LinkedIn` still redirects to `/in/` profiles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LegacyFormat {
+    /// `linkedin.com/pub/<name>/<x>/<y>/<z>`
+    PublicProfile,
+    /// `linkedin.com/profile/view?id=<id>`
+    ProfileView,
+}
+
+impl fmt::Display for LegacyFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PublicProfile => f.write_str("/pub/"),
+            Self::ProfileView => f.write_str("/profile/view?id="),
+        }
+    }
+}
+
+/// A legacy profile URL, which can only be mapped to a profile over the network.
+#[derive(Debug, Clone)]
+pub(crate) struct LegacyProfilePath {
+    pub(crate) format: LegacyFormat,
+    /// The legacy URL on `https://www.linkedin.com`, without tracking parameters
+    pub(crate) url: String,
+    /// The name or id identifying the profile in the legacy URL
+    pub(crate) identifier: String,
+}
+
+/// A URL accepted as pointing at a profile.
+#[derive(Debug, Clone)]
+pub(crate) enum ProfileTarget {
+    Modern(ProfilePath),
+    Legacy(LegacyProfilePath),
+}
+
+impl ProfileTarget {
+    /// Returns the URL to fetch when checking the profile.
+    pub(crate) fn request_url(&self) -> &str {
+        match self {
+            Self::Modern(profile) => profile.canonical.as_str(),
+            Self::Legacy(legacy) => &legacy.url,
+        }
+    }
+}
+
+/// A profile URL split into the profile it belongs to and the section it shows.
+#[derive(Debug, Clone)]
+pub(crate) struct ProfilePath {
+    pub(crate) canonical: CanonicalProfileUrl,
+    pub(crate) section: ProfileSection,
+    /// The percent-decoded username as written in the URL
+    pub(crate) username: String,
+}
+
+/// Normalizes a `LinkedIn` profile URL to its canonical form.
+///
+/// Forces `https` and the `www.linkedin.com` host, lowercases and percent-decodes the
+/// username, strips query strings and fragments, and drops locale suffixes such as
+/// `/en` and sub-pages such as `/details/experience/`. No network request is made.
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The URL format is invalid
+/// - The URL is not from an official `LinkedIn` host
+/// - The URL is not a profile URL
+/// - The username breaks one of `LinkedIn`'s [`UsernameRule`]s
+/// - The URL is a legacy profile URL, whose profile is only known after resolving it
+///   over the network (see [`LegacyFormat`])
+///
+/// # Example
+///
+/// ```
+/// use credify::normalize_profile_url;
+///
+/// let canonical = normalize_profile_url("https://www.linkedin.com/in/Jos%C3%A9-Garc%C3%ADa").unwrap();
+/// assert_eq!(canonical.username(), "josé-garcía");
+///
+/// let canonical = normalize_profile_url("https://uk.linkedin.com/in/John-Smith/?trk=public_profile#about").unwrap();
+/// assert_eq!(canonical.as_str(), "https://www.linkedin.com/in/john-smith");
+/// ```
+pub fn normalize_profile_url(url: &str) -> Result<CanonicalProfileUrl, LinkedInUrlError> {
+    Ok(parse_linkedin_url(url)?.canonical)
+}
+
+/// Tells which part of a profile a `LinkedIn` URL points at.
+///
+/// # Errors
+///
+/// Returns the same errors as [`normalize_profile_url`].
+///
+/// # Example
+///
+/// ```
+/// use credify::{ProfileSection, profile_section};
+///
+/// assert_eq!(
+///     profile_section("https://www.linkedin.com/in/jane-doe/details/experience/").unwrap(),
+///     ProfileSection::Details("experience".to_string())
+/// );
+/// assert_eq!(
+///     profile_section("https://www.linkedin.com/in/jane-doe/overlay/contact-info/").unwrap(),
+///     ProfileSection::ContactInfo
+/// );
+/// ```
+pub fn profile_section(url: &str) -> Result<ProfileSection, LinkedInUrlError> {
+    Ok(parse_linkedin_url(url)?.section)
+}
+
+fn parse_linkedin_url(url: &str) -> Result<ProfilePath, LinkedInUrlError> {
+    let parsed = Url::parse(url).map_err(|e| LinkedInUrlError::InvalidUrl(e.to_string()))?;
+    if DomainPolicy::new().url_host_variant(&parsed).is_none() {
+        return Err(LinkedInUrlError::NotLinkedInUrl);
+    }
+    match parse_profile_target(&parsed)? {
+        ProfileTarget::Modern(profile) => Ok(profile),
+        ProfileTarget::Legacy(legacy) => Err(LinkedInUrlError::LegacyProfileUrl(legacy.format)),
+    }
+}
+
+/// Parses the path of a URL whose host was already verified, accepting legacy shapes.
+pub(crate) fn parse_profile_target(url: &Url) -> Result<ProfileTarget, LinkedInUrlError> {
+    match parse_legacy_path(url) {
+        Some(legacy) => Ok(ProfileTarget::Legacy(legacy)),
+        None => parse_profile_path(url).map(ProfileTarget::Modern),
+    }
+}
+
+/// Resolves the modern profile a legacy URL was redirected to.
+///
+/// Looks at the final URL and, for unfollowed redirects, the `Location` header.
+pub(crate) fn resolve_redirect(final_url: &str, location: Option<&str>) -> Option<ProfilePath> {
+    [Some(final_url), location]
+        .into_iter()
+        .flatten()
+        .filter_map(|target| {
+            Url::parse(final_url)
+                .and_then(|base| base.join(target))
+                .ok()
+        })
+        .filter(|target| DomainPolicy::new().url_host_variant(target).is_some())
+        .find_map(|target| parse_profile_path(&target).ok())
+}
+
+fn parse_legacy_path(url: &Url) -> Option<LegacyProfilePath> {
+    let segments: Vec<&str> = url
+        .path_segments()?
+        .filter(|segment| !segment.is_empty())
+        .collect();
+
+    match segments.as_slice() {
+        ["pub", name, rest @ ..]
+            if rest.len() == 3
+                && rest
+                    .iter()
+                    .all(|part| part.bytes().all(|b| b.is_ascii_alphanumeric())) =>
+        {
+            Some(LegacyProfilePath {
+                format: LegacyFormat::PublicProfile,
+                url: format!("https://www.linkedin.com/pub/{name}/{}", rest.join("/")),
+                identifier: percent_decode_str(name).decode_utf8_lossy().into_owned(),
+            })
+        }
+        ["profile", "view"] => {
+            let id = url
+                .query_pairs()
+                .find(|(key, _)| key == "id")
+                .map(|(_, value)| value.into_owned())
+                .filter(|id| !id.is_empty())?;
+            let mut legacy_url = Url::parse("https://www.linkedin.com/profile/view").ok()?;
+            legacy_url.query_pairs_mut().append_pair("id", &id);
+            Some(LegacyProfilePath {
+                format: LegacyFormat::ProfileView,
+                url: legacy_url.into(),
+                identifier: id,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Parses the path of a URL whose host was already verified.
+pub(crate) fn parse_profile_path(url: &Url) -> Result<ProfilePath, LinkedInUrlError> {
+    let segments: Vec<&str> = url
+        .path_segments()
+        .ok_or(LinkedInUrlError::NotProfileUrl)?
+        .filter(|segment| !segment.is_empty())
+        .collect();
+
+    let ["in", vanity, rest @ ..] = segments.as_slice() else {
+        return Err(LinkedInUrlError::NotProfileUrl);
+    };
+    let section = classify_section(rest).ok_or(LinkedInUrlError::NotProfileUrl)?;
+
+    let username = percent_decode_str(vanity)
+        .decode_utf8()
+        .map_err(|_| LinkedInUrlError::InvalidUsername(UsernameRule::InvalidEncoding))?
+        .into_owned();
+    check_username(&username).map_err(LinkedInUrlError::InvalidUsername)?;
+
+    Ok(ProfilePath {
+        canonical: CanonicalProfileUrl::from_username(&username.to_lowercase()),
+        section,
+        username,
+    })
+}
+
+/// Classifies the path segments that follow the vanity name.
+fn classify_section(rest: &[&str]) -> Option<ProfileSection> {
+    match rest {
+        [] => Some(ProfileSection::Main),
+        [locale] if is_locale_segment(locale) => Some(ProfileSection::Main),
+        ["details", section] => Some(ProfileSection::Details(section.to_ascii_lowercase())),
+        ["recent-activity"] => Some(ProfileSection::RecentActivity(None)),
+        ["recent-activity", filter] => Some(ProfileSection::RecentActivity(Some(
+            filter.to_ascii_lowercase(),
+        ))),
+        ["overlay", "contact-info"] => Some(ProfileSection::ContactInfo),
+        ["overlay", name] => Some(ProfileSection::Overlay(name.to_ascii_lowercase())),
+        _ => None,
+    }
+}
+
+/// Checks the characters `LinkedIn` allows in a vanity name.
+///
+/// Letters and digits from any script are allowed, so `josé-garcía` and `张伟` are valid.
+pub(crate) fn check_username(username: &str) -> Result<(), UsernameRule> {
+    if let Some(c) = username
+        .chars()
+        .find(|&c| !(c.is_alphanumeric() || c == '-' || c == '.'))
+    {
+        return Err(UsernameRule::InvalidCharacter(c));
+    }
+
+    match username.chars().count() {
+        len if len < MIN_USERNAME_CHARS => Err(UsernameRule::TooShort(len)),
+        len if len > MAX_USERNAME_CHARS => Err(UsernameRule::TooLong(len)),
+        _ => Ok(()),
+    }
+}
+
+/// Matches locale suffixes such as `en`, `pt-br` or `zh_CN`.
+fn is_locale_segment(segment: &str) -> bool {
+    let bytes = segment.as_bytes();
+    let is_language = |part: &[u8]| part.len() == 2 && part.iter().all(u8::is_ascii_alphabetic);
+    match bytes.len() {
+        2 => is_language(bytes),
+        5 => {
+            is_language(&bytes[..2]) && matches!(bytes[2], b'-' | b'_') && is_language(&bytes[3..])
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_profile_has_one_canonical_form() {
+        let expected = "https://www.linkedin.com/in/jane-doe";
+        for url in [
+            "http://linkedin.com/in/Jane-Doe",
+            "https://www.linkedin.com/in/jane-doe/?originalSubdomain=uk",
+            "https://de.linkedin.com/in/jane-doe/en",
+            "https://m.linkedin.com/in/jane-doe/pt-br/",
+            "https://www.linkedin.com/in/jane%2Ddoe#experience",
+        ] {
+            let canonical = normalize_profile_url(url).expect(url);
+            assert_eq!(canonical.as_str(), expected, "{url}");
+            assert_eq!(canonical.username(), "jane-doe");
+        }
+    }
+
+    #[test]
+    fn test_sub_pages_resolve_to_root_profile() {
+        let cases = [
+            (
+                "https://www.linkedin.com/in/jane-doe/en",
+                ProfileSection::Main,
+            ),
+            (
+                "https://www.linkedin.com/in/jane-doe/details/experience/",
+                ProfileSection::Details("experience".to_string()),
+            ),
+            (
+                "https://www.linkedin.com/in/jane-doe/recent-activity/",
+                ProfileSection::RecentActivity(None),
+            ),
+            (
+                "https://www.linkedin.com/in/jane-doe/recent-activity/all/",
+                ProfileSection::RecentActivity(Some("all".to_string())),
+            ),
+            (
+                "https://www.linkedin.com/in/jane-doe/overlay/contact-info/",
+                ProfileSection::ContactInfo,
+            ),
+            (
+                "https://www.linkedin.com/in/jane-doe/overlay/about-this-profile/",
+                ProfileSection::Overlay("about-this-profile".to_string()),
+            ),
+        ];
+        for (url, section) in cases {
+            assert_eq!(profile_section(url).expect(url), section, "{url}");
+            assert_eq!(
+                normalize_profile_url(url).expect(url).as_str(),
+                "https://www.linkedin.com/in/jane-doe"
+            );
+        }
+    }
+
+    #[test]
+    fn test_unicode_usernames() {
+        let canonical = normalize_profile_url("https://www.linkedin.com/in/josé-garcía").unwrap();
+        assert_eq!(canonical.username(), "josé-garcía");
+
+        let canonical =
+            normalize_profile_url("https://www.linkedin.com/in/%E5%BC%A0%E4%BC%9F123").unwrap();
+        assert_eq!(canonical.username(), "张伟123");
+        assert_eq!(canonical.as_str(), "https://www.linkedin.com/in/张伟123");
+    }
+
+    #[test]
+    fn test_username_rules() {
+        let rule = |url: &str| match normalize_profile_url(url) {
+            Err(LinkedInUrlError::InvalidUsername(rule)) => rule,
+            other => panic!("expected InvalidUsername for {url}, got {other:?}"),
+        };
+
+        assert_eq!(
+            rule("https://www.linkedin.com/in/ab"),
+            UsernameRule::TooShort(2)
+        );
+        assert_eq!(
+            rule(&format!("https://www.linkedin.com/in/{}", "a".repeat(101))),
+            UsernameRule::TooLong(101)
+        );
+        assert_eq!(
+            rule("https://www.linkedin.com/in/jane_doe"),
+            UsernameRule::InvalidCharacter('_')
+        );
+        assert_eq!(
+            rule("https://www.linkedin.com/in/jane%20doe"),
+            UsernameRule::InvalidCharacter(' ')
+        );
+        assert_eq!(
+            rule("https://www.linkedin.com/in/%FF%FE%FD"),
+            UsernameRule::InvalidEncoding
+        );
+        assert!(
+            normalize_profile_url(&format!("https://www.linkedin.com/in/{}", "a".repeat(100)))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_legacy_urls() {
+        let url = Url::parse("https://uk.linkedin.com/pub/jane-doe/12/345/678?trk=x").unwrap();
+        let Ok(ProfileTarget::Legacy(legacy)) = parse_profile_target(&url) else {
+            panic!("expected a legacy /pub/ URL");
+        };
+        assert_eq!(legacy.format, LegacyFormat::PublicProfile);
+        assert_eq!(
+            legacy.url,
+            "https://www.linkedin.com/pub/jane-doe/12/345/678"
+        );
+        assert_eq!(legacy.identifier, "jane-doe");
+
+        let url = Url::parse("http://www.linkedin.com/profile/view?id=AAkAAA123&trk=nav").unwrap();
+        let Ok(ProfileTarget::Legacy(legacy)) = parse_profile_target(&url) else {
+            panic!("expected a legacy /profile/view URL");
+        };
+        assert_eq!(legacy.format, LegacyFormat::ProfileView);
+        assert_eq!(
+            legacy.url,
+            "https://www.linkedin.com/profile/view?id=AAkAAA123"
+        );
+
+        assert!(matches!(
+            normalize_profile_url("https://www.linkedin.com/pub/jane-doe/12/345/678"),
+            Err(LinkedInUrlError::LegacyProfileUrl(
+                LegacyFormat::PublicProfile
+            ))
+        ));
+        assert!(matches!(
+            normalize_profile_url("https://www.linkedin.com/profile/view"),
+            Err(LinkedInUrlError::NotProfileUrl)
+        ));
+        assert!(matches!(
+            normalize_profile_url("https://www.linkedin.com/pub/jane-doe"),
+            Err(LinkedInUrlError::NotProfileUrl)
+        ));
+    }
+
+    #[test]
+    fn test_resolve_redirect() {
+        let resolved = resolve_redirect(
+            "https://www.linkedin.com/pub/jane-doe/12/345/678",
+            Some("/in/jane-doe-12345678"),
+        )
+        .expect("Location header points at a profile");
+        assert_eq!(
+            resolved.canonical.as_str(),
+            "https://www.linkedin.com/in/jane-doe-12345678"
+        );
+
+        assert!(resolve_redirect("https://www.linkedin.com/404/", None).is_none());
+    }
+
+    #[test]
+    fn test_rejects_non_profile_urls() {
+        assert!(matches!(
+            normalize_profile_url("https://www.linkedin.com/company/microsoft"),
+            Err(LinkedInUrlError::NotProfileUrl)
+        ));
+        assert!(matches!(
+            normalize_profile_url("https://www.linkedin.com/in/"),
+            Err(LinkedInUrlError::NotProfileUrl)
+        ));
+        assert!(matches!(
+            normalize_profile_url("https://www.linkedin.com/in/jane-doe/feed/activity"),
+            Err(LinkedInUrlError::NotProfileUrl)
+        ));
+        assert!(matches!(
+            normalize_profile_url("https://linkedin.com.evil.io/in/jane-doe"),
+            Err(LinkedInUrlError::NotLinkedInUrl)
+        ));
+        assert!(matches!(
+            normalize_profile_url("not a url"),
+            Err(LinkedInUrlError::InvalidUrl(_))
+        ));
+    }
+}
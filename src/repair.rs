@@ -0,0 +1,267 @@
+//! Repair of common malformations in user-supplied `LinkedIn` URLs.
+
+use crate::DomainPolicy;
+use crate::LinkedInUrlError;
+use crate::profile_url::{CANONICAL_PREFIX, check_username};
+use crate::settings::ValidationSettings;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use url::Url;
+
+/// Quote and bracket pairs that are stripped from around a URL.
+const QUOTE_PAIRS: &[(char, char)] = &[
+    ('"', '"'),
+    ('\'', '\''),
+    ('`', '`'),
+    ('<', '>'),
+    ('\u{201c}', '\u{201d}'),
+    ('\u{2018}', '\u{2019}'),
+];
+
+/// A fix applied by [`repair_linkedin_url`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum UrlRepair {
+    /// Leading or trailing whitespace was removed
+    TrimmedWhitespace,
+    /// Surrounding quotes or angle brackets were removed
+    StrippedQuotes,
+    /// Whitespace inside the URL was dropped around `/` and replaced by `-` elsewhere
+    RemovedInnerWhitespace,
+    /// A malformed scheme such as `https//` or `https:/` was rewritten
+    FixedScheme,
+    /// The missing `https://` scheme was added
+    AddedScheme,
+    /// A bare vanity name such as `janedoe` or `@janedoe` was expanded to a profile URL
+    ExpandedUsername,
+}
+
+impl fmt::Display for UrlRepair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::TrimmedWhitespace => "trimmed surrounding whitespace",
+            Self::StrippedQuotes => "removed surrounding quotes",
+            Self::RemovedInnerWhitespace => "removed spaces inside the URL",
+            Self::FixedScheme => "fixed a malformed scheme",
+            Self::AddedScheme => "added the missing https:// scheme",
+            Self::ExpandedUsername => "expanded a bare username to a profile URL",
+        })
+    }
+}
+
+/// A URL after [`repair_linkedin_url`], with the fixes that produced it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RepairedUrl {
+    /// The repaired URL
+    pub url: String,
+    /// The fixes applied, in order; empty if the input needed none
+    pub repairs: Vec<UrlRepair>,
+}
+
+/// Repairs common malformations in a user-supplied `LinkedIn` URL.
+///
+/// Applies well-defined fixes only: surrounding whitespace and quotes are removed, spaces
+/// inside the URL are dropped (or turned into `-` inside a name), malformed schemes such as
+/// `https//` are rewritten, a missing scheme is added to `linkedin.com` URLs, and a bare
+/// vanity name (`@janedoe`, or `janedoe` without dots) becomes
+/// `https://www.linkedin.com/in/<name>`. No network request is made and the repaired URL
+/// is not validated beyond parsing.
+///
+/// # Errors
+///
+/// Returns [`LinkedInUrlError::InvalidUrl`] if the input is still not a URL after repair.
+///
+/// # Example
+///
+/// ```
+/// use credify::{UrlRepair, repair_linkedin_url};
+///
+/// let repaired = repair_linkedin_url(" https//www.linkedin.com/in/jane ").unwrap();
+/// assert_eq!(repaired.url, "https://www.linkedin.com/in/jane");
+/// assert_eq!(repaired.repairs, [UrlRepair::TrimmedWhitespace, UrlRepair::FixedScheme]);
+///
+/// let repaired = repair_linkedin_url("@janedoe").unwrap();
+/// assert_eq!(repaired.url, "https://www.linkedin.com/in/janedoe");
+/// assert_eq!(repaired.repairs, [UrlRepair::ExpandedUsername]);
+/// ```
+pub fn repair_linkedin_url(input: &str) -> Result<RepairedUrl, LinkedInUrlError> {
+    let mut repairs = Vec::new();
+
+    let trimmed = input.trim();
+    if trimmed.len() != input.len() {
+        repairs.push(UrlRepair::TrimmedWhitespace);
+    }
+    let unquoted = strip_quotes(trimmed);
+    if unquoted.len() != trimmed.len() {
+        repairs.push(UrlRepair::StrippedQuotes);
+    }
+    let mut url = remove_inner_whitespace(unquoted);
+    if url != unquoted {
+        repairs.push(UrlRepair::RemovedInnerWhitespace);
+    }
+
+    if let Some((scheme, rest, well_formed)) = split_scheme(&url) {
+        if !well_formed {
+            repairs.push(UrlRepair::FixedScheme);
+            url = format!("{scheme}://{rest}");
+        }
+    } else if let Some(rest) = url.strip_prefix("//") {
+        repairs.push(UrlRepair::AddedScheme);
+        url = format!("https://{rest}");
+    } else if is_linkedin_host(url.split('/').next().unwrap_or_default()) {
+        repairs.push(UrlRepair::AddedScheme);
+        url = format!("https://{url}");
+    } else if let Some(username) = bare_username(&url) {
+        repairs.push(UrlRepair::ExpandedUsername);
+        url = format!("{CANONICAL_PREFIX}{username}");
+    }
+
+    Url::parse(&url).map_err(|e| LinkedInUrlError::InvalidUrl(e.to_string()))?;
+    Ok(RepairedUrl { url, repairs })
+}
+
+/// Repairs `url` when the settings ask for it and it needed at least one fix.
+pub(crate) fn repair_for(url: &str, settings: &ValidationSettings) -> Option<RepairedUrl> {
+    if !settings.repair_urls {
+        return None;
+    }
+    repair_linkedin_url(url)
+        .ok()
+        .filter(|repaired| !repaired.repairs.is_empty())
+}
+
+fn strip_quotes(mut text: &str) -> &str {
+    loop {
+        let stripped = QUOTE_PAIRS.iter().find_map(|&(open, close)| {
+            text.strip_prefix(open)
+                .and_then(|inner| inner.strip_suffix(close))
+        });
+        match stripped {
+            Some(inner) => text = inner.trim(),
+            None => return text,
+        }
+    }
+}
+
+fn remove_inner_whitespace(text: &str) -> String {
+    let joined = text.split('/').map(str::trim).collect::<Vec<_>>().join("/");
+    joined.split_whitespace().collect::<Vec<_>>().join("-")
+}
+
+/// Splits `http`/`https` off the front of `url`.
+///
+/// Returns the lowercase scheme, the rest after any `:` and slashes, and whether the
+/// scheme was already written as `scheme://`. Returns `None` when there is no scheme.
+fn split_scheme(url: &str) -> Option<(&'static str, &str, bool)> {
+    let scheme = ["https", "http"].into_iter().find(|scheme| {
+        url.get(..scheme.len())
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(scheme))
+    })?;
+    let after_scheme = &url[scheme.len()..];
+    let (has_colon, after_colon) = match after_scheme.strip_prefix(':') {
+        Some(rest) => (true, rest),
+        None => (false, after_scheme),
+    };
+    let rest = after_colon.trim_start_matches('/');
+    let slashes = after_colon.len() - rest.len();
+    if !has_colon && slashes == 0 {
+        // A word such as `httpster`, not a scheme
+        return None;
+    }
+    Some((scheme, rest, has_colon && slashes == 2))
+}
+
+fn is_linkedin_host(host: &str) -> bool {
+    DomainPolicy::new().host_variant(host).is_some()
+}
+
+/// Returns the vanity name in `@janedoe` or `janedoe`.
+///
+/// Names without `@` must not contain dots, so domains such as `example.com` are left alone.
+fn bare_username(text: &str) -> Option<&str> {
+    let (username, prefixed) = match text.strip_prefix('@') {
+        Some(username) => (username, true),
+        None => (text, false),
+    };
+    if username.contains(['/', ':']) || (!prefixed && username.contains('.')) {
+        return None;
+    }
+    check_username(username).ok().map(|()| username)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repair(input: &str) -> (String, Vec<UrlRepair>) {
+        let repaired = repair_linkedin_url(input).expect(input);
+        (repaired.url, repaired.repairs)
+    }
+
+    #[test]
+    fn test_repairs() {
+        use UrlRepair::*;
+
+        for (input, url, repairs) in [
+            (
+                "https://www.linkedin.com/in/jane",
+                "https://www.linkedin.com/in/jane",
+                vec![],
+            ),
+            (
+                "linkedin.com/in/janedoe",
+                "https://linkedin.com/in/janedoe",
+                vec![AddedScheme],
+            ),
+            (
+                "https://linkedin.com/in/ jane doe",
+                "https://linkedin.com/in/jane-doe",
+                vec![RemovedInnerWhitespace],
+            ),
+            (
+                "https//www.linkedin.com/in/jane",
+                "https://www.linkedin.com/in/jane",
+                vec![FixedScheme],
+            ),
+            (
+                "HTTPS:/uk.linkedin.com/in/jane",
+                "https://uk.linkedin.com/in/jane",
+                vec![FixedScheme],
+            ),
+            (
+                "  \"<www.linkedin.com/in/jane>\"\n",
+                "https://www.linkedin.com/in/jane",
+                vec![TrimmedWhitespace, StrippedQuotes, AddedScheme],
+            ),
+            (
+                "//linkedin.com/in/jane",
+                "https://linkedin.com/in/jane",
+                vec![AddedScheme],
+            ),
+            (
+                "@jane.doe",
+                "https://www.linkedin.com/in/jane.doe",
+                vec![ExpandedUsername],
+            ),
+            (
+                "janedoe",
+                "https://www.linkedin.com/in/janedoe",
+                vec![ExpandedUsername],
+            ),
+        ] {
+            assert_eq!(repair(input), (url.to_string(), repairs), "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_unrepairable_input() {
+        for input in ["", "   ", "example.com", "jd", "not a url at all/x"] {
+            assert!(
+                matches!(
+                    repair_linkedin_url(input),
+                    Err(LinkedInUrlError::InvalidUrl(_))
+                ),
+                "{input:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,910 @@
+//! Report builders shared by the sync and async entry points.
+//!
+//! Every entry point feeds the outcome of its validation into the builders in this
+//! module, so the AI result and the LLM report are identical no matter which API
+//! produced them.
+
+use crate::{
+    AIDecision, AIValidationResult, EntityKind, HostVariant, LinkedInEntity, LinkedInUrlError,
+    ProfileStatus, RepairedUrl, ValidationMetadata, ValidationOutcome, classify_linkedin_url,
+    profile_url::{ProfileTarget, parse_profile_target},
+    settings::ValidationSettings,
+    short_link::is_short_link,
+};
+use serde_json::json;
+use std::future::Future;
+use url::Url;
+
+/// Builds the AI result for `url`, running `validate` only if the format checks pass.
+pub(crate) fn ai_result(
+    url: &str,
+    settings: &ValidationSettings,
+    validate: impl FnOnce() -> Result<ValidationOutcome, LinkedInUrlError>,
+) -> AIValidationResult {
+    let timestamp = chrono::Utc::now().to_rfc3339();
+    match ai_precheck(url, settings, &timestamp) {
+        Ok(profile) => profile.into_result(validate(), timestamp),
+        Err(result) => *result,
+    }
+}
+
+/// Async counterpart of [`ai_result`]. `validation` is only awaited if the format checks pass.
+pub(crate) async fn ai_result_async(
+    url: &str,
+    settings: &ValidationSettings,
+    validation: impl Future<Output = Result<ValidationOutcome, LinkedInUrlError>>,
+) -> AIValidationResult {
+    let timestamp = chrono::Utc::now().to_rfc3339();
+    match ai_precheck(url, settings, &timestamp) {
+        Ok(profile) => profile.into_result(validation.await, timestamp),
+        Err(result) => *result,
+    }
+}
+
+/// Confidence kept by accepted results whose input had to be repaired first.
+const REPAIRED_CONFIDENCE_FACTOR: f32 = 0.85;
+
+/// Records the repairs made before validation in an AI result.
+///
+/// Accepted results report "accepted after repair" with reduced confidence, since the
+/// repaired URL may not be the one the user meant.
+pub(crate) fn after_repair(
+    mut result: AIValidationResult,
+    repaired: Option<RepairedUrl>,
+) -> AIValidationResult {
+    let Some(repaired) = repaired else {
+        return result;
+    };
+    if result.decision == AIDecision::Accept {
+        let repairs: Vec<String> = repaired.repairs.iter().map(ToString::to_string).collect();
+        result.confidence *= REPAIRED_CONFIDENCE_FACTOR;
+        result.reason = format!(
+            "Accepted after repair ({}): {}",
+            repairs.join(", "),
+            result.reason
+        );
+    }
+    result.metadata.repair = Some(repaired);
+    result
+}
+
+/// Serializes an AI result as pretty JSON, falling back to a minimal error object.
+pub(crate) fn ai_json(result: &AIValidationResult) -> String {
+    serde_json::to_string_pretty(result).unwrap_or_else(|_| {
+        json!({
+            "error": "Failed to serialize result",
+            "is_valid": false
+        })
+        .to_string()
+    })
+}
+
+/// What the format checks learned about a profile URL.
+///
+/// Legacy URLs and short links leave out what is only known once they are resolved.
+struct CheckedProfile {
+    username: Option<String>,
+    canonical_url: Option<String>,
+    host_variant: Option<HostVariant>,
+}
+
+impl CheckedProfile {
+    fn into_result(
+        mut self,
+        validation: Result<ValidationOutcome, LinkedInUrlError>,
+        timestamp: String,
+    ) -> AIValidationResult {
+        let mut short_link = None;
+        let mut summary = None;
+        if let Ok(outcome) = &validation {
+            if outcome.legacy_format.is_some() || outcome.short_link.is_some() {
+                self.username = Some(outcome.username.clone());
+                self.canonical_url = Some(outcome.canonical_url.clone());
+            }
+            self.host_variant = Some(outcome.host_variant.clone());
+            short_link.clone_from(&outcome.short_link);
+            summary.clone_from(&outcome.summary);
+        }
+        let mut result = ai_result_from_validation(self.username, validation, timestamp);
+        result.canonical_url = self.canonical_url;
+        result.metadata.host_variant = self.host_variant;
+        result.profile_summary = summary;
+        if let Some(short_link) = short_link {
+            result.metadata.original_url = Some(short_link.url);
+            result.metadata.resolved_url = Some(short_link.resolved_url);
+        }
+        result
+    }
+}
+
+/// Checks the URL format and extracts the username, canonical URL and host variant.
+///
+/// Returns the final result when the URL cannot be a `LinkedIn` profile.
+fn ai_precheck(
+    url: &str,
+    settings: &ValidationSettings,
+    timestamp: &str,
+) -> Result<CheckedProfile, Box<AIValidationResult>> {
+    // First check URL format
+    let parsed_url = match Url::parse(url) {
+        Ok(u) => u,
+        Err(e) => {
+            return Err(Box::new(AIValidationResult {
+                is_valid: false,
+                confidence: 1.0,
+                decision: AIDecision::Reject,
+                username: None,
+                canonical_url: None,
+                reason: format!("Invalid URL format: {e}"),
+                profile_summary: None,
+                metadata: ValidationMetadata {
+                    url_format_valid: false,
+                    domain_verified: false,
+                    profile_pattern_matched: false,
+                    error_type: Some("INVALID_URL".to_string()),
+                    timestamp: timestamp.to_string(),
+                    ..ValidationMetadata::default()
+                },
+            }));
+        }
+    };
+
+    // Short links are only checked once they are resolved
+    if settings.resolve_short_links && is_short_link(&parsed_url) {
+        return Ok(CheckedProfile {
+            username: None,
+            canonical_url: None,
+            host_variant: None,
+        });
+    }
+
+    // Check domain
+    let Some(host_variant) = settings.domain_policy.url_host_variant(&parsed_url) else {
+        return Err(Box::new(AIValidationResult {
+            is_valid: false,
+            confidence: 1.0,
+            decision: AIDecision::Reject,
+            username: None,
+            canonical_url: None,
+            reason: "Not a LinkedIn URL".to_string(),
+            profile_summary: None,
+            metadata: ValidationMetadata {
+                url_format_valid: true,
+                domain_verified: false,
+                profile_pattern_matched: false,
+                error_type: Some("WRONG_DOMAIN".to_string()),
+                timestamp: timestamp.to_string(),
+                ..ValidationMetadata::default()
+            },
+        }));
+    };
+
+    // Check profile pattern
+    let profile = match parse_profile_target(&parsed_url) {
+        Ok(ProfileTarget::Modern(profile)) => profile,
+        Ok(ProfileTarget::Legacy(_)) => {
+            return Ok(CheckedProfile {
+                username: None,
+                canonical_url: None,
+                host_variant: Some(host_variant),
+            });
+        }
+        Err(LinkedInUrlError::InvalidUsername(rule)) => {
+            return Err(Box::new(AIValidationResult {
+                is_valid: false,
+                confidence: 0.95,
+                decision: AIDecision::Reject,
+                username: None,
+                canonical_url: None,
+                reason: format!("Invalid LinkedIn username: {rule}"),
+                profile_summary: None,
+                metadata: ValidationMetadata {
+                    url_format_valid: true,
+                    domain_verified: true,
+                    profile_pattern_matched: false,
+                    error_type: Some("INVALID_USERNAME".to_string()),
+                    timestamp: timestamp.to_string(),
+                    ..ValidationMetadata::default()
+                },
+            }));
+        }
+        Err(_) => {
+            let reason = match classify_linkedin_url(url) {
+                LinkedInEntity::Page { kind, id } => {
+                    format!("LinkedIn URL but not a profile: it points to the {kind} '{id}'")
+                }
+                _ => "LinkedIn URL but not a profile (might be a company page, job posting or \
+                      other LinkedIn content)"
+                    .to_string(),
+            };
+            return Err(Box::new(AIValidationResult {
+                is_valid: false,
+                confidence: 0.95,
+                decision: AIDecision::Reject,
+                username: None,
+                canonical_url: None,
+                reason,
+                profile_summary: None,
+                metadata: ValidationMetadata {
+                    url_format_valid: true,
+                    domain_verified: true,
+                    profile_pattern_matched: false,
+                    error_type: Some("NOT_PROFILE".to_string()),
+                    timestamp: timestamp.to_string(),
+                    ..ValidationMetadata::default()
+                },
+            }));
+        }
+    };
+
+    Ok(CheckedProfile {
+        username: Some(profile.username),
+        canonical_url: Some(profile.canonical.into_string()),
+        host_variant: Some(host_variant),
+    })
+}
+
+fn ai_result_from_validation(
+    username: Option<String>,
+    validation: Result<ValidationOutcome, LinkedInUrlError>,
+    timestamp: String,
+) -> AIValidationResult {
+    let (status, http_status) = match validation {
+        Ok(outcome) => (outcome.status, outcome.http_status),
+        Err(e) => return ai_result_from_error(username, e, timestamp),
+    };
+
+    match status {
+        ProfileStatus::Exists => AIValidationResult {
+            is_valid: true,
+            confidence: 1.0,
+            decision: AIDecision::Accept,
+            username,
+            canonical_url: None,
+            reason: "Verified LinkedIn profile exists".to_string(),
+            profile_summary: None,
+            metadata: ValidationMetadata {
+                url_format_valid: true,
+                domain_verified: true,
+                profile_pattern_matched: true,
+                http_status: Some(http_status),
+                error_type: None,
+                timestamp,
+                ..ValidationMetadata::default()
+            },
+        },
+        ProfileStatus::LikelyExists { .. } => {
+            // This is actually a GOOD sign - LinkedIn auth walls mean real profiles
+            AIValidationResult {
+                is_valid: true,
+                confidence: 0.9,
+                decision: AIDecision::Accept,
+                username,
+                canonical_url: None,
+                reason: "LinkedIn profile likely exists (auth required)".to_string(),
+                profile_summary: None,
+                metadata: ValidationMetadata {
+                    url_format_valid: true,
+                    domain_verified: true,
+                    profile_pattern_matched: true,
+                    http_status: Some(http_status),
+                    error_type: Some("AUTH_REQUIRED".to_string()),
+                    timestamp,
+                    ..ValidationMetadata::default()
+                },
+            }
+        }
+        ProfileStatus::NotFound => AIValidationResult {
+            is_valid: false,
+            confidence: 0.95,
+            decision: AIDecision::Reject,
+            username,
+            canonical_url: None,
+            reason: "LinkedIn profile does not exist (404)".to_string(),
+            profile_summary: None,
+            metadata: ValidationMetadata {
+                url_format_valid: true,
+                domain_verified: true,
+                profile_pattern_matched: true,
+                http_status: Some(http_status),
+                error_type: Some("NOT_FOUND".to_string()),
+                timestamp,
+                ..ValidationMetadata::default()
+            },
+        },
+        ProfileStatus::RateLimited { .. } | ProfileStatus::Blocked | ProfileStatus::Unknown => {
+            // LinkedIn did not show us the profile, so nothing is known yet
+            let (reason, error_type) = match status {
+                ProfileStatus::RateLimited { .. } => ("Rate limited by LinkedIn", "RATE_LIMITED"),
+                ProfileStatus::Blocked => ("Request blocked by LinkedIn", "BLOCKED"),
+                _ => ("Unexpected response from LinkedIn", "UNKNOWN_RESPONSE"),
+            };
+            AIValidationResult {
+                is_valid: true,
+                confidence: 0.5,
+                decision: AIDecision::Retry,
+                username,
+                canonical_url: None,
+                reason: format!("{reason} - retry later"),
+                profile_summary: None,
+                metadata: ValidationMetadata {
+                    url_format_valid: true,
+                    domain_verified: true,
+                    profile_pattern_matched: true,
+                    http_status: Some(http_status),
+                    error_type: Some(error_type.to_string()),
+                    timestamp,
+                    ..ValidationMetadata::default()
+                },
+            }
+        }
+    }
+}
+
+fn ai_result_from_error(
+    username: Option<String>,
+    error: LinkedInUrlError,
+    timestamp: String,
+) -> AIValidationResult {
+    match error {
+        LinkedInUrlError::NetworkError(_) | LinkedInUrlError::TransportError(_) => {
+            AIValidationResult {
+                is_valid: true,
+                confidence: 0.6,
+                decision: AIDecision::Retry,
+                username,
+                canonical_url: None,
+                reason: "Network error - retry later".to_string(),
+                profile_summary: None,
+                metadata: ValidationMetadata {
+                    url_format_valid: true,
+                    domain_verified: true,
+                    profile_pattern_matched: true,
+                    error_type: Some("NETWORK_ERROR".to_string()),
+                    timestamp,
+                    ..ValidationMetadata::default()
+                },
+            }
+        }
+        LinkedInUrlError::ClientBuildError(_) => {
+            // Can't create validator, but URL format is good
+            AIValidationResult {
+                is_valid: true,
+                confidence: 0.7,
+                decision: AIDecision::Accept,
+                username,
+                canonical_url: None,
+                reason: "URL format is valid (network check unavailable)".to_string(),
+                profile_summary: None,
+                metadata: ValidationMetadata {
+                    url_format_valid: true,
+                    domain_verified: true,
+                    profile_pattern_matched: true,
+                    error_type: Some("VALIDATOR_ERROR".to_string()),
+                    timestamp,
+                    ..ValidationMetadata::default()
+                },
+            }
+        }
+        LinkedInUrlError::LegacyProfileUrl(format) => {
+            // Legacy URLs still redirect to live profiles, but this one was not followed
+            AIValidationResult {
+                is_valid: true,
+                confidence: 0.6,
+                decision: AIDecision::Accept,
+                username,
+                canonical_url: None,
+                reason: format!(
+                    "Legacy LinkedIn {format} profile URL (not resolved to an /in/ URL)"
+                ),
+                profile_summary: None,
+                metadata: ValidationMetadata {
+                    url_format_valid: true,
+                    domain_verified: true,
+                    profile_pattern_matched: true,
+                    error_type: Some("LEGACY_PROFILE_URL".to_string()),
+                    timestamp,
+                    ..ValidationMetadata::default()
+                },
+            }
+        }
+        LinkedInUrlError::UnresolvedShortLink(failure) => AIValidationResult {
+            is_valid: false,
+            confidence: 0.9,
+            decision: AIDecision::Reject,
+            username,
+            canonical_url: None,
+            reason: format!("Short link could not be resolved: {failure}"),
+            profile_summary: None,
+            metadata: ValidationMetadata {
+                url_format_valid: true,
+                domain_verified: true,
+                profile_pattern_matched: false,
+                error_type: Some("SHORT_LINK_UNRESOLVED".to_string()),
+                timestamp,
+                ..ValidationMetadata::default()
+            },
+        },
+        // The format checks passed, so these come from the URL a short link resolved to
+        e @ (LinkedInUrlError::InvalidUrl(_)
+        | LinkedInUrlError::NotLinkedInUrl
+        | LinkedInUrlError::NotProfileUrl
+        | LinkedInUrlError::InvalidUsername(_)) => AIValidationResult {
+            is_valid: false,
+            confidence: 0.95,
+            decision: AIDecision::Reject,
+            username,
+            canonical_url: None,
+            reason: format!("Short link does not lead to a LinkedIn profile: {e}"),
+            profile_summary: None,
+            metadata: ValidationMetadata {
+                url_format_valid: true,
+                domain_verified: true,
+                profile_pattern_matched: false,
+                error_type: Some("NOT_PROFILE".to_string()),
+                timestamp,
+                ..ValidationMetadata::default()
+            },
+        },
+        e => AIValidationResult {
+            is_valid: false,
+            confidence: 0.2,
+            decision: AIDecision::Reject,
+            username,
+            canonical_url: None,
+            reason: format!("Validation error: {e}"),
+            profile_summary: None,
+            metadata: ValidationMetadata {
+                url_format_valid: true,
+                domain_verified: true,
+                profile_pattern_matched: true,
+                error_type: Some("OTHER_ERROR".to_string()),
+                timestamp,
+                ..ValidationMetadata::default()
+            },
+        },
+    }
+}
+
+/// Builds the verbose LLM report for `url` from the outcome of its validation.
+pub(crate) fn llm_report(
+    url: &str,
+    validation: Result<ValidationOutcome, LinkedInUrlError>,
+) -> String {
+    let mut result = String::new();
+
+    // Header
+    result.push_str("=== LINKEDIN PROFILE VALIDATION REPORT ===\n\n");
+    result.push_str(&format!("TIMESTAMP: {}\n", chrono::Utc::now().to_rfc3339()));
+    result.push_str(&format!("INPUT_URL: {url}\n"));
+    result.push('\n');
+
+    let username = validation
+        .as_ref()
+        .ok()
+        .map(|outcome| outcome.username.clone());
+    let resolved_url = validation
+        .as_ref()
+        .ok()
+        .filter(|outcome| outcome.legacy_format.is_some() || outcome.short_link.is_some())
+        .map(|outcome| outcome.canonical_url.clone());
+    let short_link = validation
+        .as_ref()
+        .ok()
+        .and_then(|outcome| outcome.short_link.as_ref())
+        .map(|link| link.url.clone());
+    let validation = match validation.map(|outcome| outcome.status) {
+        Ok(
+            status @ (ProfileStatus::RateLimited { .. }
+            | ProfileStatus::Blocked
+            | ProfileStatus::Unknown),
+        ) => {
+            push_unavailable_section(&mut result, &status);
+            result.push_str("\n=== END OF VALIDATION REPORT ===\n");
+            return result;
+        }
+        Ok(status) => status.into_result(),
+        Err(e) => Err(e),
+    };
+
+    match validation {
+        Ok(_) => {
+            result.push_str("VALIDATION_RESULT: SUCCESS\n");
+            result.push_str("VALIDATION_STATUS: PASSED\n");
+            result.push_str("PROFILE_EXISTS: TRUE\n");
+            result.push_str("URL_FORMAT: VALID\n");
+            result.push_str("DOMAIN_VERIFIED: TRUE\n");
+            result.push_str("PROFILE_ACCESSIBLE: TRUE\n");
+
+            if let Some(username) = &username {
+                result.push_str(&format!("LINKEDIN_USERNAME: {username}\n"));
+            }
+            if let Some(short_link) = &short_link {
+                result.push_str(&format!("SHORT_LINK: {short_link}\n"));
+            }
+            if let Some(resolved_url) = &resolved_url {
+                result.push_str(&format!("RESOLVED_PROFILE_URL: {resolved_url}\n"));
+            }
+
+            result.push_str("\nDETAILED_EXPLANATION:\n");
+            result.push_str("The provided URL has been successfully validated. The LinkedIn profile exists and is accessible. ");
+            result.push_str("The URL follows the correct LinkedIn profile format and the domain has been verified as authentic. ");
+            result.push_str("The profile page returned a successful response, confirming the profile is active and publicly viewable.\n");
+
+            result.push_str("\nSUGGESTED_ACTIONS:\n");
+            result.push_str("1. Proceed with profile data extraction using LinkedIn API or web scraping tools\n");
+            result.push_str("2. Cache this validation result to avoid repeated network requests\n");
+            result.push_str(
+                "3. Store the profile URL in your database as a verified LinkedIn profile\n",
+            );
+            result.push_str(
+                "4. Consider extracting additional profile metadata (name, headline, etc.)\n",
+            );
+            result.push_str(
+                "5. Set up monitoring to periodically re-validate the profile existence\n",
+            );
+
+            result.push_str("\nRECOMMENDED_NEXT_STEP: Extract profile data using appropriate LinkedIn data extraction methods\n");
+        }
+        Err(e) => {
+            result.push_str("VALIDATION_RESULT: ERROR\n");
+            result.push_str("VALIDATION_STATUS: FAILED\n");
+
+            match e {
+                LinkedInUrlError::InvalidUrl(ref msg) => {
+                    result.push_str("ERROR_TYPE: INVALID_URL_FORMAT\n");
+                    result.push_str(&format!("ERROR_MESSAGE: {e}\n"));
+                    result.push_str(&format!("ERROR_DETAILS: {msg}\n"));
+                    result.push_str("ERROR_SEVERITY: HIGH\n");
+                    result.push_str("PROFILE_EXISTS: UNKNOWN\n");
+                    result.push_str("URL_FORMAT: INVALID\n");
+
+                    result.push_str("\nDETAILED_EXPLANATION:\n");
+                    result.push_str("The provided string is not a valid URL. The URL parser failed to interpret the input as a properly formatted URL. ");
+                    result.push_str("Common causes include missing protocol (http/https), invalid characters, or malformed structure.\n");
+
+                    result.push_str("\nSUGGESTED_ACTIONS:\n");
+                    result.push_str("1. Ensure the URL starts with 'https://' or 'http://'\n");
+                    result.push_str("2. Check for special characters that need URL encoding\n");
+                    result.push_str("3. Verify there are no spaces or line breaks in the URL\n");
+                    result.push_str(
+                        "4. Confirm the URL follows standard format: protocol://domain/path\n",
+                    );
+                    result.push_str(
+                        "5. Try URL encoding the input if it contains special characters\n",
+                    );
+                    result.push_str(
+                        "6. Example valid format: https://www.linkedin.com/in/username\n",
+                    );
+
+                    result.push_str(
+                        "\nRECOMMENDED_NEXT_STEP: Fix the URL format and retry validation\n",
+                    );
+                }
+                LinkedInUrlError::NotLinkedInUrl => {
+                    result.push_str("ERROR_TYPE: NOT_LINKEDIN_DOMAIN\n");
+                    result.push_str(&format!("ERROR_MESSAGE: {e}\n"));
+                    result.push_str("ERROR_SEVERITY: MEDIUM\n");
+                    result.push_str("PROFILE_EXISTS: NOT_APPLICABLE\n");
+                    result.push_str("URL_FORMAT: VALID\n");
+                    result.push_str("DOMAIN_VERIFIED: FALSE\n");
+
+                    // Extract the actual domain from URL
+                    if let Ok(parsed_url) = url::Url::parse(url) {
+                        if let Some(domain) = parsed_url.domain() {
+                            result.push_str(&format!("ACTUAL_DOMAIN: {domain}\n"));
+                        }
+                    }
+
+                    result.push_str("\nDETAILED_EXPLANATION:\n");
+                    result
+                        .push_str("The URL is properly formatted but does not point to LinkedIn. ");
+                    result.push_str("Only URLs from official LinkedIn hosts (linkedin.com, www.linkedin.com, m.linkedin.com and country hosts such as uk.linkedin.com) are accepted for LinkedIn profile validation. ");
+                    result.push_str("The provided URL points to a different domain.\n");
+
+                    result.push_str("\nSUGGESTED_ACTIONS:\n");
+                    result.push_str("1. Verify the URL is meant to be a LinkedIn profile URL\n");
+                    result.push_str("2. Check if the URL was copied correctly from LinkedIn\n");
+                    result
+                        .push_str("3. Ensure the domain is 'linkedin.com' or one of its official subdomains\n");
+                    result.push_str("4. Look for the correct LinkedIn profile URL in the user's social media links\n");
+                    result.push_str(
+                        "5. Ask the user to provide their LinkedIn profile URL directly\n",
+                    );
+
+                    result.push_str("\nRECOMMENDED_NEXT_STEP: Obtain the correct LinkedIn profile URL from the user or source\n");
+                }
+                LinkedInUrlError::NotProfileUrl => {
+                    result.push_str("ERROR_TYPE: NOT_PROFILE_URL\n");
+                    result.push_str(&format!("ERROR_MESSAGE: {e}\n"));
+                    result.push_str("ERROR_SEVERITY: MEDIUM\n");
+                    result.push_str("PROFILE_EXISTS: NOT_APPLICABLE\n");
+                    result.push_str("URL_FORMAT: VALID\n");
+                    result.push_str("DOMAIN_VERIFIED: TRUE\n");
+                    result.push_str("URL_TYPE: NON_PROFILE_LINKEDIN_URL\n");
+                    let entity = classify_linkedin_url(url);
+                    if let LinkedInEntity::Page { kind, id } = &entity {
+                        let kind_code = kind.to_string().to_uppercase().replace(' ', "_");
+                        result.push_str(&format!("LINKEDIN_ENTITY: {kind_code}\n"));
+                        result.push_str(&format!("ENTITY_IDENTIFIER: {id}\n"));
+                    }
+
+                    result.push_str("\nDETAILED_EXPLANATION:\n");
+                    result
+                        .push_str("The URL points to LinkedIn but is not a personal profile URL. ");
+                    match &entity {
+                        LinkedInEntity::Page { kind, id } => result.push_str(&format!(
+                            "It points to the LinkedIn {kind} '{id}', not to a person. "
+                        )),
+                        _ => result.push_str(
+                            "It might be a company page, job posting, or other LinkedIn content. ",
+                        ),
+                    }
+                    result.push_str(
+                        "Valid profile URLs follow the pattern: linkedin.com/in/username\n",
+                    );
+
+                    result.push_str("\nSUGGESTED_ACTIONS:\n");
+                    if entity.kind() == Some(EntityKind::Company) {
+                        result.push_str("1. Check the company page with validate_company_url\n");
+                    } else {
+                        result.push_str(
+                            "1. If this is a company page URL (contains '/company/'), check it with validate_company_url\n",
+                        );
+                    }
+                    result.push_str("2. Verify if this is a job posting URL (contains '/jobs/')\n");
+                    result.push_str(
+                        "3. Look for the '/in/' segment that indicates a personal profile\n",
+                    );
+                    result
+                        .push_str("4. Navigate to the person's actual profile page on LinkedIn\n");
+                    result.push_str("5. Use LinkedIn search to find the correct profile URL\n");
+
+                    result.push_str("\nRECOMMENDED_NEXT_STEP: Navigate to the personal profile section of LinkedIn\n");
+                }
+                LinkedInUrlError::NotCompanyUrl => {
+                    result.push_str("ERROR_TYPE: NOT_COMPANY_URL\n");
+                    result.push_str(&format!("ERROR_MESSAGE: {e}\n"));
+                    result.push_str("ERROR_SEVERITY: MEDIUM\n");
+                    result.push_str("PROFILE_EXISTS: NOT_APPLICABLE\n");
+                    result.push_str("URL_FORMAT: VALID\n");
+                    result.push_str("DOMAIN_VERIFIED: TRUE\n");
+
+                    result.push_str("\nDETAILED_EXPLANATION:\n");
+                    result.push_str("The URL points to LinkedIn but is not a company page URL. ");
+                    result.push_str(
+                        "Valid company page URLs follow the pattern: linkedin.com/company/name\n",
+                    );
+
+                    result.push_str("\nSUGGESTED_ACTIONS:\n");
+                    result.push_str("1. Validate personal profile URLs (containing '/in/') as profiles instead\n");
+                    result.push_str("2. Use LinkedIn search to find the company page URL\n");
+
+                    result.push_str("\nRECOMMENDED_NEXT_STEP: Obtain the company page URL\n");
+                }
+                LinkedInUrlError::InvalidUsername(ref rule) => {
+                    result.push_str("ERROR_TYPE: INVALID_USERNAME\n");
+                    result.push_str(&format!("ERROR_MESSAGE: {e}\n"));
+                    result.push_str(&format!("ERROR_DETAILS: {rule}\n"));
+                    result.push_str("ERROR_SEVERITY: MEDIUM\n");
+                    result.push_str("PROFILE_EXISTS: FALSE\n");
+                    result.push_str("URL_FORMAT: VALID\n");
+                    result.push_str("DOMAIN_VERIFIED: TRUE\n");
+
+                    result.push_str("\nDETAILED_EXPLANATION:\n");
+                    result.push_str("The URL has the shape of a LinkedIn profile URL, but the username cannot belong to a LinkedIn profile. ");
+                    result.push_str("LinkedIn usernames have 3 to 100 letters, digits, hyphens or dots; letters from any script are allowed.\n");
+
+                    result.push_str("\nSUGGESTED_ACTIONS:\n");
+                    result.push_str("1. Check the username for typos or truncation\n");
+                    result.push_str(
+                        "2. Remove spaces and special characters copied along with the URL\n",
+                    );
+                    result.push_str("3. Search for the person on LinkedIn using their name\n");
+
+                    result.push_str("\nRECOMMENDED_NEXT_STEP: Correct the username or search for the correct profile URL\n");
+                }
+                LinkedInUrlError::LegacyProfileUrl(format) => {
+                    result.push_str("ERROR_TYPE: LEGACY_PROFILE_URL\n");
+                    result.push_str(&format!("ERROR_MESSAGE: {e}\n"));
+                    result.push_str(&format!("ERROR_DETAILS: {format}\n"));
+                    result.push_str("ERROR_SEVERITY: LOW\n");
+                    result.push_str("PROFILE_EXISTS: UNKNOWN\n");
+                    result.push_str("URL_FORMAT: VALID\n");
+                    result.push_str("DOMAIN_VERIFIED: TRUE\n");
+
+                    result.push_str("\nDETAILED_EXPLANATION:\n");
+                    result.push_str(
+                        "The URL uses a profile format LinkedIn retired in favour of /in/ URLs. ",
+                    );
+                    result.push_str("LinkedIn still redirects most legacy URLs to the current profile, but legacy resolution is disabled for this validator.\n");
+
+                    result.push_str("\nSUGGESTED_ACTIONS:\n");
+                    result.push_str("1. Enable legacy URL resolution to follow the redirect to the /in/ profile\n");
+                    result.push_str(
+                        "2. Open the URL in a browser and copy the /in/ URL it lands on\n",
+                    );
+                    result.push_str("3. Replace the stored URL with the resolved /in/ URL\n");
+
+                    result.push_str(
+                        "\nRECOMMENDED_NEXT_STEP: Resolve the legacy URL to its /in/ profile URL\n",
+                    );
+                }
+                LinkedInUrlError::UnresolvedShortLink(ref failure) => {
+                    result.push_str("ERROR_TYPE: SHORT_LINK_UNRESOLVED\n");
+                    result.push_str(&format!("ERROR_MESSAGE: {e}\n"));
+                    result.push_str(&format!("ERROR_DETAILS: {failure}\n"));
+                    result.push_str("ERROR_SEVERITY: MEDIUM\n");
+                    result.push_str("PROFILE_EXISTS: UNKNOWN\n");
+                    result.push_str("URL_FORMAT: VALID\n");
+
+                    result.push_str("\nDETAILED_EXPLANATION:\n");
+                    result.push_str("The URL is a lnkd.in short link, but following its redirects did not lead to a LinkedIn URL. ");
+                    result.push_str("The link may have expired, redirect in a loop, or pass through more redirects than allowed.\n");
+
+                    result.push_str("\nSUGGESTED_ACTIONS:\n");
+                    result.push_str("1. Open the short link in a browser to see where it leads\n");
+                    result
+                        .push_str("2. Ask the sender for the full linkedin.com/in/ profile URL\n");
+                    result.push_str(
+                        "3. Raise the short link hop limit if the chain is legitimately long\n",
+                    );
+
+                    result.push_str("\nRECOMMENDED_NEXT_STEP: Obtain the full LinkedIn profile URL behind the short link\n");
+                }
+                LinkedInUrlError::ProfileNotFound => {
+                    result.push_str("ERROR_TYPE: PROFILE_NOT_FOUND\n");
+                    result.push_str(&format!("ERROR_MESSAGE: {e}\n"));
+                    result.push_str("ERROR_SEVERITY: LOW\n");
+                    result.push_str("PROFILE_EXISTS: FALSE\n");
+                    result.push_str("URL_FORMAT: VALID\n");
+                    result.push_str("DOMAIN_VERIFIED: TRUE\n");
+                    result.push_str("HTTP_STATUS: 404\n");
+
+                    result.push_str("\nDETAILED_EXPLANATION:\n");
+                    result.push_str("The URL format is correct and points to LinkedIn, but the profile does not exist. ");
+                    result
+                        .push_str("LinkedIn returned a 404 error or redirected to an error page. ");
+                    result.push_str("This means the username in the URL does not correspond to any active LinkedIn profile.\n");
+
+                    result.push_str("\nSUGGESTED_ACTIONS:\n");
+                    result.push_str("1. Double-check the username/URL for typos\n");
+                    result.push_str(
+                        "2. Verify if the profile might have been deleted or deactivated\n",
+                    );
+                    result.push_str("3. Check if the user might have changed their LinkedIn URL\n");
+                    result.push_str("4. Search for the person on LinkedIn using their name, or check the profile URLs suggest_profile_urls generates from it\n");
+                    result.push_str(
+                        "5. Contact the person to get their current LinkedIn profile URL\n",
+                    );
+                    result.push_str("6. Remove this URL from your database if it's stored\n");
+
+                    result.push_str("\nRECOMMENDED_NEXT_STEP: Search for the correct profile or mark as invalid in your system\n");
+                }
+                LinkedInUrlError::AuthenticationRequired => {
+                    result.push_str("ERROR_TYPE: AUTH_REQUIRED\n");
+                    result.push_str(&format!("ERROR_MESSAGE: {e}\n"));
+                    result.push_str("ERROR_SEVERITY: MEDIUM\n");
+                    result.push_str("PROFILE_EXISTS: UNKNOWN\n");
+                    result.push_str("URL_FORMAT: VALID\n");
+                    result.push_str("DOMAIN_VERIFIED: TRUE\n");
+                    result.push_str("LINKEDIN_RESPONSE: AUTHENTICATION_WALL\n");
+
+                    result.push_str("\nDETAILED_EXPLANATION:\n");
+                    result.push_str("LinkedIn is requiring authentication to view this profile. ");
+                    result.push_str("This typically happens when LinkedIn detects automated access patterns or when accessing from certain IP ranges. ");
+                    result.push_str("The profile format is valid and the profile LIKELY EXISTS, but LinkedIn is preventing automated verification.\n");
+
+                    result.push_str("\nSUGGESTED_ACTIONS:\n");
+                    result.push_str(
+                        "1. ACCEPT AS VALID: The URL format is correct and points to LinkedIn - treat as valid profile URL\n",
+                    );
+                    result.push_str(
+                        "2. RETRY LATER: Wait 30-60 seconds before trying to validate this URL again\n",
+                    );
+                    result.push_str("3. CONTINUE SEARCH: This URL is likely correct, but continue searching for alternative profiles if needed\n");
+                    result.push_str("4. CONFIDENCE: HIGH - LinkedIn auth walls usually indicate real profiles\n");
+                    result.push_str("5. FOR AI AGENTS: You can safely return this URL as a valid LinkedIn profile\n");
+                    result.push_str(
+                        "6. VERIFICATION: The URL follows pattern https://linkedin.com/in/USERNAME which is correct\n",
+                    );
+                    result.push_str(
+                        "7. RECOMMENDATION: Use this URL - it's almost certainly a valid profile\n",
+                    );
+
+                    result.push_str("\nRECOMMENDED_NEXT_STEP: ACCEPT this URL as valid - LinkedIn authentication requirements typically indicate the profile exists\n");
+                }
+                LinkedInUrlError::NetworkError(_) | LinkedInUrlError::TransportError(_) => {
+                    result.push_str("ERROR_TYPE: NETWORK_ERROR\n");
+                    result.push_str(&format!("ERROR_MESSAGE: {e}\n"));
+                    if let LinkedInUrlError::NetworkError(ref network_err) = e {
+                        result.push_str(&format!("NETWORK_ERROR_DETAILS: {network_err}\n"));
+                    }
+                    result.push_str("ERROR_SEVERITY: HIGH\n");
+                    result.push_str("PROFILE_EXISTS: UNKNOWN\n");
+                    result.push_str("URL_FORMAT: VALID\n");
+                    result.push_str("NETWORK_STATUS: FAILED\n");
+
+                    result.push_str("\nDETAILED_EXPLANATION:\n");
+                    result.push_str("Failed to establish a network connection to LinkedIn. ");
+                    result.push_str("This could be due to network connectivity issues, DNS resolution problems, ");
+                    result.push_str(
+                        "firewall restrictions, or LinkedIn being temporarily unavailable.\n",
+                    );
+
+                    result.push_str("\nSUGGESTED_ACTIONS:\n");
+                    result.push_str("1. Check internet connectivity with a simple ping test\n");
+                    result.push_str("2. Verify DNS resolution for linkedin.com\n");
+                    result.push_str("3. Check firewall settings for outbound HTTPS connections\n");
+                    result.push_str("4. Test if LinkedIn is accessible from a web browser\n");
+                    result.push_str("5. Implement retry logic with exponential backoff\n");
+                    result.push_str("6. Check for any proxy configuration requirements\n");
+                    result.push_str("7. Monitor LinkedIn's status page for any outages\n");
+
+                    result.push_str("\nRECOMMENDED_NEXT_STEP: Diagnose and resolve network connectivity issues\n");
+                }
+                LinkedInUrlError::ClientBuildError(ref msg) => {
+                    result.push_str("ERROR_TYPE: CLIENT_BUILD_ERROR\n");
+                    result.push_str(&format!("ERROR_MESSAGE: {e}\n"));
+                    result.push_str(&format!("ERROR_DETAILS: {msg}\n"));
+                    result.push_str("ERROR_SEVERITY: CRITICAL\n");
+                    result.push_str("PROFILE_EXISTS: UNKNOWN\n");
+
+                    result.push_str("\nDETAILED_EXPLANATION:\n");
+                    result.push_str("Failed to build the HTTP client needed for validation. ");
+                    result.push_str("This is an internal error that prevents any network requests from being made. ");
+                    result.push_str("Common causes include TLS configuration issues or system resource constraints.\n");
+
+                    result.push_str("\nSUGGESTED_ACTIONS:\n");
+                    result.push_str("1. Check system TLS/SSL library installation\n");
+                    result.push_str("2. Verify sufficient memory is available\n");
+                    result.push_str("3. Check for any security software blocking connections\n");
+                    result.push_str("4. Review system logs for detailed error information\n");
+                    result.push_str("5. Restart the application or service\n");
+                    result.push_str("6. Update system libraries and dependencies\n");
+
+                    result.push_str("\nRECOMMENDED_NEXT_STEP: Resolve system configuration issues before retry\n");
+                }
+            }
+        }
+    }
+
+    result.push_str("\n=== END OF VALIDATION REPORT ===\n");
+    result
+}
+
+/// Report section for responses where `LinkedIn` did not show the profile at all.
+fn push_unavailable_section(result: &mut String, status: &ProfileStatus) {
+    result.push_str("VALIDATION_RESULT: INCONCLUSIVE\n");
+    result.push_str("VALIDATION_STATUS: RETRY\n");
+    match status {
+        ProfileStatus::RateLimited { retry_after } => {
+            result.push_str("ERROR_TYPE: RATE_LIMITED\n");
+            if let Some(retry_after) = retry_after {
+                result.push_str(&format!("RETRY_AFTER_SECONDS: {}\n", retry_after.as_secs()));
+            }
+        }
+        ProfileStatus::Blocked => result.push_str("ERROR_TYPE: BLOCKED\n"),
+        _ => result.push_str("ERROR_TYPE: UNKNOWN_RESPONSE\n"),
+    }
+    result.push_str("ERROR_SEVERITY: MEDIUM\n");
+    result.push_str("PROFILE_EXISTS: UNKNOWN\n");
+    result.push_str("URL_FORMAT: VALID\n");
+    result.push_str("DOMAIN_VERIFIED: TRUE\n");
+
+    result.push_str("\nDETAILED_EXPLANATION:\n");
+    result.push_str("The URL format is correct, but LinkedIn did not serve the profile page. ");
+    result.push_str(
+        "LinkedIn either rate limited or refused the request, or answered in an unexpected way, ",
+    );
+    result.push_str("so it is not yet known whether the profile exists.\n");
+
+    result.push_str("\nSUGGESTED_ACTIONS:\n");
+    result.push_str(
+        "1. Wait before validating this URL again (honour RETRY_AFTER_SECONDS if present)\n",
+    );
+    result.push_str("2. Reduce the rate of validation requests\n");
+    result.push_str("3. Do not treat the profile as missing based on this result\n");
+
+    result.push_str("\nRECOMMENDED_NEXT_STEP: Retry validation later\n");
+}
@@ -0,0 +1,266 @@
+//! Retries of requests that failed for transient reasons.
+
+use crate::{HttpResponse, LinkedInUrlError};
+use std::time::{Duration, Instant};
+
+/// Status codes retried by default: server errors and gateway failures.
+const DEFAULT_RETRY_STATUSES: &[u16] = &[500, 502, 503, 504];
+
+/// How the delay between attempts grows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryBackoff {
+    /// The same delay before every retry
+    Constant(Duration),
+    /// The delay grows by the given step with each retry: 1×, 2×, 3×, ...
+    Linear(Duration),
+    /// The delay doubles with each retry, starting at `base` and capped at `max`
+    Exponential {
+        /// Delay before the first retry
+        base: Duration,
+        /// Longest delay between two attempts
+        max: Duration,
+    },
+}
+
+impl RetryBackoff {
+    /// Returns the delay before retry number `retry` (1-based).
+    fn delay(self, retry: u32) -> Duration {
+        match self {
+            Self::Constant(delay) => delay,
+            Self::Linear(step) => step.saturating_mul(retry),
+            Self::Exponential { base, max } => base
+                .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)))
+                .min(max),
+        }
+    }
+}
+
+/// Which request errors are retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryableErrors {
+    /// Errors are never retried, only the configured status codes
+    None,
+    /// Connection failures and timeouts of the `reqwest` transports
+    ConnectionAndTimeout,
+    /// Every [`LinkedInUrlError::NetworkError`] and [`LinkedInUrlError::TransportError`]
+    All,
+}
+
+/// How a validator retries requests that failed for transient reasons.
+///
+/// A page request is attempted up to `max_attempts` times. It is retried when the
+/// transport fails with a retryable error or `LinkedIn` answers with a retryable status
+/// code, as long as the next attempt can start before the deadline. The number of
+/// attempts is reported in [`ValidationMetadata::attempts`](crate::ValidationMetadata::attempts).
+///
+/// # Example
+///
+/// ```
+/// use credify::{RetryBackoff, RetryPolicy, RetryableErrors};
+/// use std::time::Duration;
+///
+/// let policy = RetryPolicy::new()
+///     .max_attempts(5)
+///     .backoff(RetryBackoff::Linear(Duration::from_millis(500)))
+///     .retry_errors(RetryableErrors::ConnectionAndTimeout)
+///     .retry_statuses([502, 503])
+///     .deadline(Duration::from_secs(20));
+/// assert_eq!(policy.attempts(), 5);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    backoff: RetryBackoff,
+    errors: RetryableErrors,
+    statuses: Vec<u16>,
+    deadline: Option<Duration>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RetryPolicy {
+    /// Creates the default policy: 3 attempts with exponential backoff from 500ms up to
+    /// 5s, retrying every transport error and 500, 502, 503 and 504, without a deadline.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            max_attempts: 3,
+            backoff: RetryBackoff::Exponential {
+                base: Duration::from_millis(500),
+                max: Duration::from_secs(5),
+            },
+            errors: RetryableErrors::All,
+            statuses: DEFAULT_RETRY_STATUSES.to_vec(),
+            deadline: None,
+        }
+    }
+
+    /// Sets how many times a request is attempted in total, including the first attempt.
+    /// Values below 1 are treated as 1.
+    #[must_use]
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets how the delay between attempts grows.
+    #[must_use]
+    pub const fn backoff(mut self, backoff: RetryBackoff) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Sets which request errors are retried.
+    #[must_use]
+    pub const fn retry_errors(mut self, errors: RetryableErrors) -> Self {
+        self.errors = errors;
+        self
+    }
+
+    /// Replaces the status codes that are retried.
+    #[must_use]
+    pub fn retry_statuses(mut self, statuses: impl IntoIterator<Item = u16>) -> Self {
+        self.statuses = statuses.into_iter().collect();
+        self
+    }
+
+    /// Gives up once `deadline` has passed since the first attempt, even if attempts remain.
+    #[must_use]
+    pub const fn deadline(mut self, deadline: Duration) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Returns how many times a request is attempted in total.
+    #[must_use]
+    pub const fn attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Returns how long to wait before retrying after attempt number `attempt` (1-based)
+    /// produced `result`, or `None` if the result is final.
+    pub(crate) fn delay_after(
+        &self,
+        result: &Result<HttpResponse, LinkedInUrlError>,
+        attempt: u32,
+        started: Instant,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts || !self.is_retryable(result) {
+            return None;
+        }
+        let delay = self.backoff.delay(attempt);
+        match self.deadline {
+            Some(deadline) if started.elapsed() + delay >= deadline => None,
+            _ => Some(delay),
+        }
+    }
+
+    fn is_retryable(&self, result: &Result<HttpResponse, LinkedInUrlError>) -> bool {
+        match (result, self.errors) {
+            (Ok(response), _) => self.statuses.contains(&response.status),
+            (Err(_), RetryableErrors::None) => false,
+            (Err(LinkedInUrlError::NetworkError(e)), RetryableErrors::ConnectionAndTimeout) => {
+                e.is_connect() || e.is_timeout()
+            }
+            (Err(LinkedInUrlError::NetworkError(_) | LinkedInUrlError::TransportError(_)), _) => {
+                self.errors == RetryableErrors::All
+            }
+            (Err(_), _) => false,
+        }
+    }
+}
+
+/// Attempts made while validating one URL.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Attempts {
+    pub(crate) count: u32,
+    pub(crate) started: Instant,
+}
+
+impl Attempts {
+    pub(crate) fn new() -> Self {
+        Self {
+            count: 0,
+            started: Instant::now(),
+        }
+    }
+
+    /// Returns the number of attempts, or `None` if no request was made.
+    pub(crate) fn reported(self) -> Option<u32> {
+        (self.count > 0).then_some(self.count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ok(status: u16) -> Result<HttpResponse, LinkedInUrlError> {
+        Ok(HttpResponse::new(
+            status,
+            "https://www.linkedin.com/in/johndoe",
+        ))
+    }
+
+    fn transport_error() -> Result<HttpResponse, LinkedInUrlError> {
+        Err(LinkedInUrlError::TransportError(
+            "connection reset".to_string(),
+        ))
+    }
+
+    #[test]
+    fn test_backoff_curves() {
+        let second = Duration::from_secs(1);
+        assert_eq!(RetryBackoff::Constant(second).delay(3), second);
+        assert_eq!(RetryBackoff::Linear(second).delay(3), 3 * second);
+        let exponential = RetryBackoff::Exponential {
+            base: second,
+            max: 5 * second,
+        };
+        assert_eq!(exponential.delay(1), second);
+        assert_eq!(exponential.delay(3), 4 * second);
+        assert_eq!(exponential.delay(30), 5 * second);
+    }
+
+    #[test]
+    fn test_what_is_retried() {
+        let policy = RetryPolicy::new().backoff(RetryBackoff::Constant(Duration::ZERO));
+        let started = Instant::now();
+
+        assert!(policy.delay_after(&ok(503), 1, started).is_some());
+        assert!(policy.delay_after(&transport_error(), 2, started).is_some());
+        assert!(policy.delay_after(&transport_error(), 3, started).is_none());
+        assert!(policy.delay_after(&ok(200), 1, started).is_none());
+        assert!(policy.delay_after(&ok(404), 1, started).is_none());
+        assert!(
+            policy
+                .delay_after(&Err(LinkedInUrlError::NotProfileUrl), 1, started)
+                .is_none()
+        );
+
+        let statuses_only = policy.clone().retry_errors(RetryableErrors::None);
+        assert!(
+            statuses_only
+                .delay_after(&transport_error(), 1, started)
+                .is_none()
+        );
+        let connection_only = policy.retry_errors(RetryableErrors::ConnectionAndTimeout);
+        assert!(
+            connection_only
+                .delay_after(&transport_error(), 1, started)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_deadline_stops_retries() {
+        let policy = RetryPolicy::new()
+            .backoff(RetryBackoff::Constant(Duration::from_secs(2)))
+            .deadline(Duration::from_secs(1));
+        assert!(policy.delay_after(&ok(503), 1, Instant::now()).is_none());
+    }
+}
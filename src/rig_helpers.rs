@@ -61,7 +61,7 @@ pub async fn rig_validate(url: &str) -> RigValidationResult {
         ),
         (AIDecision::Reject, false, _) if result.metadata.domain_verified => (
             "❌ Not a LinkedIn profile URL".to_string(),
-            "This is LinkedIn but not a profile - might be company page".to_string(),
+            "This is LinkedIn but not a profile - if it is a company page, check it with validate_company_url".to_string(),
         ),
         (AIDecision::Reject, false, _) => (
             "❌ Invalid LinkedIn URL".to_string(),
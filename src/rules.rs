@@ -0,0 +1,526 @@
+//! Data-driven rules for reading `LinkedIn` responses.
+
+use crate::ProfileStatus;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::path::Path;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Rules used when no others are configured.
+pub(crate) static DEFAULT_RULES: Lazy<DetectionRules> = Lazy::new(|| {
+    DetectionRules::from_toml(DetectionRules::DEFAULT_TOML).expect("default rules are valid")
+});
+
+/// Reason reported for profiles hidden behind the authwall.
+const AUTHWALL_REASON: &str = "LinkedIn requires authentication to view this profile";
+
+/// Errors that can occur while loading detection rules.
+#[derive(Error, Debug)]
+pub enum DetectionRulesError {
+    /// The rules file could not be read.
+    #[error("[RULES_IO_ERROR] The detection rules could not be read: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The rules file has a format other than TOML or JSON.
+    #[error("[RULES_UNSUPPORTED_FORMAT] Detection rules must be a .toml or .json file: {0}")]
+    UnsupportedFormat(String),
+
+    /// The rules are not valid TOML or JSON, or do not have the expected shape.
+    #[error("[RULES_PARSE_ERROR] The detection rules could not be parsed: {0}")]
+    Parse(String),
+
+    /// A rule has no matchers, so it would match every response.
+    #[error("[RULES_EMPTY_RULE] Detection rule '{0}' has no matchers")]
+    EmptyRule(String),
+
+    /// A `body_regex` matcher is not a valid regular expression.
+    #[error("[RULES_INVALID_REGEX] Detection rule '{rule}' has an invalid regex: {message}")]
+    InvalidRegex {
+        /// Name of the rule
+        rule: String,
+        /// Why the regex was rejected
+        message: String,
+    },
+}
+
+/// What a response matched by a rule says about the profile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleOutcome {
+    /// [`ProfileStatus::NotFound`]
+    NotFound,
+    /// [`ProfileStatus::LikelyExists`]: `LinkedIn` only hides existing profiles
+    Authwall,
+    /// [`ProfileStatus::RateLimited`], with the delay of the `Retry-After` header
+    RateLimited,
+    /// [`ProfileStatus::Blocked`]
+    Blocked,
+    /// [`ProfileStatus::Exists`]
+    Exists,
+    /// [`ProfileStatus::Unknown`]
+    Unknown,
+}
+
+/// One condition on a response.
+///
+/// Text matchers are case-sensitive substring checks, except `body_regex`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleMatcher {
+    /// The body contains the text
+    BodyContains(String),
+    /// The body matches the regular expression
+    BodyRegex(String),
+    /// The header is present, and contains the text if one is given
+    Header {
+        /// Header name, compared without case
+        name: String,
+        /// Text the header value must contain
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        contains: Option<String>,
+    },
+    /// The response has this status code
+    Status(u16),
+    /// The URL of the response, after redirects were followed, contains the text
+    FinalUrl(String),
+    /// The response is a redirect that was not followed, to a URL containing the text
+    RedirectTo(String),
+}
+
+/// A named rule deciding the status of the responses it matches.
+///
+/// A rule matches when every matcher in `all` matches and, if `any` is not empty, at
+/// least one matcher in `any` does.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DetectionRule {
+    /// Name of the rule; rules with the same name replace each other when merged
+    pub name: String,
+    /// Status reported for matching responses
+    pub outcome: RuleOutcome,
+    /// Rules with a higher priority are tried first
+    #[serde(default)]
+    pub priority: i32,
+    /// UI language of the page text the rule reads, such as `de`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    /// Matchers that must all match
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub all: Vec<RuleMatcher>,
+    /// Matchers of which at least one must match
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub any: Vec<RuleMatcher>,
+}
+
+/// Shape of a rules file.
+#[derive(Debug, Serialize, Deserialize)]
+struct RulesFile {
+    rules: Vec<DetectionRule>,
+}
+
+/// The rules deciding what a `LinkedIn` response says about a profile.
+///
+/// The 404 pages, authwall markers and status codes `LinkedIn` uses are kept as data, so a
+/// change in `LinkedIn`'s pages can be handled by loading new rules instead of upgrading
+/// the crate. The built-in rules are in [`DEFAULT_TOML`](Self::DEFAULT_TOML); rules are
+/// tried from the highest priority down, in file order for equal priorities, and the first
+/// match decides. Responses no rule matches exist if their status is 2xx and are unknown
+/// otherwise.
+///
+/// # Example
+///
+/// ```
+/// use credify::{DetectionRules, ProfileStatus};
+///
+/// let patch = DetectionRules::from_toml(r#"
+///     [[rules]]
+///     name = "not-found-banner"
+///     outcome = "not_found"
+///     priority = 55
+///     any = [{ body_regex = "(?i)profile (is )?unavailable" }]
+/// "#).expect("rules are valid");
+/// let rules = DetectionRules::default().merge(patch);
+///
+/// let status = rules.classify(200, "https://www.linkedin.com/in/johndoe", &[], "Profile unavailable");
+/// assert_eq!(status, ProfileStatus::NotFound);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DetectionRules {
+    rules: Vec<DetectionRule>,
+    /// Compiled rules, highest priority first
+    compiled: Vec<CompiledRule>,
+}
+
+impl Default for DetectionRules {
+    fn default() -> Self {
+        DEFAULT_RULES.clone()
+    }
+}
+
+impl DetectionRules {
+    /// The built-in rules, as TOML. A starting point for custom rules files.
+    pub const DEFAULT_TOML: &'static str = include_str!("detection_rules.toml");
+
+    /// Creates a rule set from `rules`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a rule has no matchers or an invalid regex.
+    pub fn new(rules: Vec<DetectionRule>) -> Result<Self, DetectionRulesError> {
+        let mut compiled = rules
+            .iter()
+            .map(CompiledRule::new)
+            .collect::<Result<Vec<_>, _>>()?;
+        // Stable, so rules of equal priority keep their order
+        compiled.sort_by_key(|rule| Reverse(rule.priority));
+        Ok(Self { rules, compiled })
+    }
+
+    /// Parses rules from TOML with a `[[rules]]` table per rule.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the TOML does not describe valid rules.
+    pub fn from_toml(toml: &str) -> Result<Self, DetectionRulesError> {
+        let file: RulesFile =
+            toml::from_str(toml).map_err(|e| DetectionRulesError::Parse(e.to_string()))?;
+        Self::new(file.rules)
+    }
+
+    /// Parses rules from JSON shaped like `{"rules": [...]}`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the JSON does not describe valid rules.
+    pub fn from_json(json: &str) -> Result<Self, DetectionRulesError> {
+        let file: RulesFile =
+            serde_json::from_str(json).map_err(|e| DetectionRulesError::Parse(e.to_string()))?;
+        Self::new(file.rules)
+    }
+
+    /// Reads rules from a `.toml` or `.json` file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, has another extension or does not
+    /// describe valid rules.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, DetectionRulesError> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("toml") => Self::from_toml(&std::fs::read_to_string(path)?),
+            Some("json") => Self::from_json(&std::fs::read_to_string(path)?),
+            _ => Err(DetectionRulesError::UnsupportedFormat(
+                path.display().to_string(),
+            )),
+        }
+    }
+
+    /// Adds the rules of `other`, replacing the rules that have the same name.
+    #[must_use]
+    pub fn merge(self, other: Self) -> Self {
+        let mut rules = self.rules;
+        let mut compiled = self.compiled;
+        for (rule, replacement) in other.rules.into_iter().zip(other.compiled) {
+            rules.retain(|existing| existing.name != rule.name);
+            compiled.retain(|existing| existing.name != rule.name);
+            rules.push(rule);
+            compiled.push(replacement);
+        }
+        compiled.sort_by_key(|rule| Reverse(rule.priority));
+        Self { rules, compiled }
+    }
+
+    /// Keeps only the rules for the given UI languages, such as `["en", "de"]`.
+    ///
+    /// Rules without a locale, which read status codes and URLs rather than page text, are
+    /// always kept. Useful when a language's phrases also appear on real profiles.
+    #[must_use]
+    pub fn only_locales(mut self, locales: &[&str]) -> Self {
+        let keep = |locale: &Option<String>| {
+            locale.as_deref().is_none_or(|locale| {
+                locales
+                    .iter()
+                    .any(|wanted| wanted.eq_ignore_ascii_case(locale))
+            })
+        };
+        self.rules.retain(|rule| keep(&rule.locale));
+        self.compiled.retain(|rule| keep(&rule.locale));
+        self
+    }
+
+    /// Returns the rules, in the order they were given.
+    #[must_use]
+    pub fn rules(&self) -> &[DetectionRule] {
+        &self.rules
+    }
+
+    /// Classifies a `LinkedIn` profile response with these rules.
+    ///
+    /// Takes the same arguments as [`classify_response`](crate::classify_response), which
+    /// uses the built-in rules.
+    #[must_use]
+    pub fn classify(
+        &self,
+        status: u16,
+        final_url: &str,
+        headers: &[(String, String)],
+        body: &str,
+    ) -> ProfileStatus {
+        let response = Response {
+            status,
+            final_url,
+            headers,
+            body,
+            // When redirects are not followed the destination is only in the Location header
+            redirect_target: if (300..400).contains(&status) {
+                find_header(headers, "location")
+            } else {
+                None
+            },
+        };
+        let outcome = self
+            .compiled
+            .iter()
+            .find(|rule| rule.matches(&response))
+            .map(|rule| rule.outcome);
+
+        match outcome {
+            Some(RuleOutcome::NotFound) => ProfileStatus::NotFound,
+            Some(RuleOutcome::Authwall) => ProfileStatus::LikelyExists {
+                reason: AUTHWALL_REASON.to_string(),
+            },
+            Some(RuleOutcome::RateLimited) => ProfileStatus::RateLimited {
+                retry_after: find_header(headers, "retry-after").and_then(parse_retry_after),
+            },
+            Some(RuleOutcome::Blocked) => ProfileStatus::Blocked,
+            Some(RuleOutcome::Exists) => ProfileStatus::Exists,
+            Some(RuleOutcome::Unknown) => ProfileStatus::Unknown,
+            None if (200..300).contains(&status) => ProfileStatus::Exists,
+            None => ProfileStatus::Unknown,
+        }
+    }
+}
+
+/// The parts of a response the matchers look at.
+struct Response<'a> {
+    status: u16,
+    final_url: &'a str,
+    headers: &'a [(String, String)],
+    body: &'a str,
+    redirect_target: Option<&'a str>,
+}
+
+#[derive(Debug, Clone)]
+struct CompiledRule {
+    name: String,
+    locale: Option<String>,
+    outcome: RuleOutcome,
+    priority: i32,
+    all: Vec<CompiledMatcher>,
+    any: Vec<CompiledMatcher>,
+}
+
+impl CompiledRule {
+    fn new(rule: &DetectionRule) -> Result<Self, DetectionRulesError> {
+        if rule.all.is_empty() && rule.any.is_empty() {
+            return Err(DetectionRulesError::EmptyRule(rule.name.clone()));
+        }
+        let compile = |matchers: &[RuleMatcher]| {
+            matchers
+                .iter()
+                .map(|matcher| CompiledMatcher::new(matcher, &rule.name))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        Ok(Self {
+            name: rule.name.clone(),
+            locale: rule.locale.clone(),
+            outcome: rule.outcome,
+            priority: rule.priority,
+            all: compile(&rule.all)?,
+            any: compile(&rule.any)?,
+        })
+    }
+
+    fn matches(&self, response: &Response<'_>) -> bool {
+        self.all.iter().all(|matcher| matcher.matches(response))
+            && (self.any.is_empty() || self.any.iter().any(|matcher| matcher.matches(response)))
+    }
+}
+
+#[derive(Debug, Clone)]
+enum CompiledMatcher {
+    BodyContains(String),
+    BodyRegex(Regex),
+    Header {
+        name: String,
+        contains: Option<String>,
+    },
+    Status(u16),
+    FinalUrl(String),
+    RedirectTo(String),
+}
+
+impl CompiledMatcher {
+    fn new(matcher: &RuleMatcher, rule: &str) -> Result<Self, DetectionRulesError> {
+        Ok(match matcher {
+            RuleMatcher::BodyContains(text) => Self::BodyContains(text.clone()),
+            RuleMatcher::BodyRegex(pattern) => {
+                Self::BodyRegex(Regex::new(pattern).map_err(|e| {
+                    DetectionRulesError::InvalidRegex {
+                        rule: rule.to_string(),
+                        message: e.to_string(),
+                    }
+                })?)
+            }
+            RuleMatcher::Header { name, contains } => Self::Header {
+                name: name.clone(),
+                contains: contains.clone(),
+            },
+            RuleMatcher::Status(status) => Self::Status(*status),
+            RuleMatcher::FinalUrl(text) => Self::FinalUrl(text.clone()),
+            RuleMatcher::RedirectTo(text) => Self::RedirectTo(text.clone()),
+        })
+    }
+
+    fn matches(&self, response: &Response<'_>) -> bool {
+        match self {
+            Self::BodyContains(text) => response.body.contains(text.as_str()),
+            Self::BodyRegex(regex) => regex.is_match(response.body),
+            Self::Header { name, contains } => find_header(response.headers, name)
+                .is_some_and(|value| contains.as_deref().is_none_or(|text| value.contains(text))),
+            Self::Status(status) => response.status == *status,
+            Self::FinalUrl(text) => response.final_url.contains(text.as_str()),
+            Self::RedirectTo(text) => response
+                .redirect_target
+                .is_some_and(|target| target.contains(text.as_str())),
+        }
+    }
+}
+
+fn find_header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(header, _)| header.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+/// Parses the delay-seconds form of `Retry-After`; HTTP dates are ignored.
+fn parse_retry_after(value: &str) -> Option<Duration> {
+    value.trim().parse().ok().map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROFILE_URL: &str = "https://www.linkedin.com/in/johndoe";
+
+    #[test]
+    fn test_rules_load_from_toml_and_json() {
+        let toml = r#"
+            [[rules]]
+            name = "gone"
+            outcome = "not_found"
+            all = [{ status = 200 }, { header = { name = "X-Li-Fabric", contains = "gone" } }]
+        "#;
+        let json = r#"{"rules": [{"name": "gone", "outcome": "not_found",
+            "all": [{"status": 200}, {"header": {"name": "X-Li-Fabric", "contains": "gone"}}]}]}"#;
+        let from_toml = DetectionRules::from_toml(toml).expect("TOML rules are valid");
+        let from_json = DetectionRules::from_json(json).expect("JSON rules are valid");
+        assert_eq!(from_toml.rules(), from_json.rules());
+
+        let headers = vec![("x-li-fabric".to_string(), "prod-gone".to_string())];
+        assert_eq!(
+            from_toml.classify(200, PROFILE_URL, &headers, ""),
+            ProfileStatus::NotFound
+        );
+        assert_eq!(
+            from_toml.classify(200, PROFILE_URL, &[], ""),
+            ProfileStatus::Exists
+        );
+    }
+
+    #[test]
+    fn test_invalid_rules_are_rejected() {
+        let empty = r#"[[rules]]
+            name = "empty"
+            outcome = "blocked""#;
+        assert!(matches!(
+            DetectionRules::from_toml(empty),
+            Err(DetectionRulesError::EmptyRule(name)) if name == "empty"
+        ));
+
+        let bad_regex = r#"[[rules]]
+            name = "bad"
+            outcome = "blocked"
+            any = [{ body_regex = "(" }]"#;
+        assert!(matches!(
+            DetectionRules::from_toml(bad_regex),
+            Err(DetectionRulesError::InvalidRegex { .. })
+        ));
+
+        assert!(matches!(
+            DetectionRules::from_json("{\"rules\": [{\"name\": \"x\"}]}"),
+            Err(DetectionRulesError::Parse(_))
+        ));
+        assert!(matches!(
+            DetectionRules::load("rules.yaml"),
+            Err(DetectionRulesError::UnsupportedFormat(_))
+        ));
+    }
+
+    #[test]
+    fn test_merge_replaces_rules_by_name() {
+        let patch = DetectionRules::from_toml(
+            r#"[[rules]]
+            name = "blocked"
+            outcome = "rate_limited"
+            priority = 80
+            any = [{ status = 403 }]"#,
+        )
+        .expect("rules are valid");
+        let rules = DetectionRules::default().merge(patch);
+
+        assert_eq!(rules.rules().len(), DetectionRules::default().rules().len());
+        assert!(matches!(
+            rules.classify(403, PROFILE_URL, &[], ""),
+            ProfileStatus::RateLimited { .. }
+        ));
+    }
+
+    #[test]
+    fn test_localized_not_found_pages() {
+        let pages = [
+            "<h1>Diese Seite existiert nicht</h1>",
+            "<h1>Cette page n&#39;existe pas</h1>",
+            "<h1>Esta página no existe</h1>",
+            "<h1>Esta página não existe</h1>",
+            "<h1>Questa pagina non esiste</h1>",
+            "<h1>Deze pagina bestaat niet</h1>",
+        ];
+        let rules = DetectionRules::default();
+        for page in pages {
+            assert_eq!(
+                rules.classify(200, PROFILE_URL, &[], page),
+                ProfileStatus::NotFound,
+                "{page}"
+            );
+        }
+
+        let english_only = DetectionRules::default().only_locales(&["en"]);
+        assert_eq!(
+            english_only.classify(200, PROFILE_URL, &[], pages[0]),
+            ProfileStatus::Exists
+        );
+        assert_eq!(
+            english_only.classify(404, PROFILE_URL, &[], ""),
+            ProfileStatus::NotFound
+        );
+    }
+}
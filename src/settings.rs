@@ -0,0 +1,79 @@
+//! URL handling settings shared by the validators and the builder.
+
+use crate::DetectionRules;
+use crate::DomainPolicy;
+use crate::HttpRequest;
+use crate::RetryPolicy;
+use crate::cache::ResultCache;
+use crate::rules::DEFAULT_RULES;
+use crate::short_link::DEFAULT_MAX_SHORT_LINK_HOPS;
+use crate::throttle::Throttle;
+use std::sync::Arc;
+
+/// How a validator interprets the URLs it is given and paces its requests.
+#[derive(Debug, Clone)]
+pub(crate) struct ValidationSettings {
+    /// Which hosts are accepted as `LinkedIn`
+    pub(crate) domain_policy: DomainPolicy,
+    /// Whether legacy `/pub/` and `/profile/view?id=` URLs are followed to `/in/` profiles
+    pub(crate) resolve_legacy_urls: bool,
+    /// Whether `lnkd.in` short links are followed before the profile checks
+    pub(crate) resolve_short_links: bool,
+    /// Redirects followed while resolving a short link before giving up
+    pub(crate) max_short_link_hops: usize,
+    /// Whether malformed input is repaired before AI validation
+    pub(crate) repair_urls: bool,
+    /// Whether the public details of served profile pages are read
+    pub(crate) fetch_profile_summary: bool,
+    /// Backoff and circuit breaker state, shared by every clone of the settings
+    pub(crate) throttle: Option<Arc<Throttle>>,
+    /// Retries of page requests that fail for transient reasons
+    pub(crate) retry: Option<RetryPolicy>,
+    /// Cached AI results, shared by every clone of the settings
+    pub(crate) cache: Option<Arc<ResultCache>>,
+    /// Rules reading the responses, if not the built-in ones
+    pub(crate) detection_rules: Option<Arc<DetectionRules>>,
+    /// `Accept-Language` sent with page requests, if any
+    pub(crate) accept_language: Option<String>,
+}
+
+impl Default for ValidationSettings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ValidationSettings {
+    /// Creates the default settings: every official host, legacy URLs resolved, short
+    /// links rejected, no repairs, no profile summaries, no throttling, no retries, no
+    /// cache, the built-in detection rules and no `Accept-Language`.
+    pub(crate) const fn new() -> Self {
+        Self {
+            domain_policy: DomainPolicy::new(),
+            resolve_legacy_urls: true,
+            resolve_short_links: false,
+            max_short_link_hops: DEFAULT_MAX_SHORT_LINK_HOPS,
+            repair_urls: false,
+            fetch_profile_summary: false,
+            throttle: None,
+            retry: None,
+            cache: None,
+            detection_rules: None,
+            accept_language: None,
+        }
+    }
+
+    /// Returns the rules reading the responses.
+    pub(crate) fn detection_rules(&self) -> &DetectionRules {
+        self.detection_rules.as_deref().unwrap_or(&DEFAULT_RULES)
+    }
+
+    /// Builds the request for a profile or company page.
+    pub(crate) fn page_request(&self, url: &str) -> HttpRequest {
+        let request = HttpRequest::get(url);
+        match &self.accept_language {
+            Some(language) => request.header("Accept-Language", language.as_str()),
+            None => request,
+        }
+    }
+}
@@ -0,0 +1,267 @@
+//! Resolution of `lnkd.in` short links.
+//!
+//! A short link is followed one redirect at a time, so a redirect chain can be cut
+//! off after a fixed number of hops and a loop is detected as soon as a URL repeats.
+
+use crate::{AsyncTransport, DomainPolicy, HttpRequest, HttpResponse, LinkedInUrlError, Transport};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use url::Url;
+
+/// Host of `LinkedIn`'s URL shortener.
+const SHORT_LINK_HOST: &str = "lnkd.in";
+
+/// Redirects followed while resolving a short link before giving up.
+pub(crate) const DEFAULT_MAX_SHORT_LINK_HOPS: usize = 5;
+
+/// A short link and the URL its redirect chain led to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShortLink {
+    /// The `lnkd.in` URL that was given
+    pub url: String,
+    /// The first `LinkedIn` URL the redirect chain reached
+    pub resolved_url: String,
+    /// Number of redirects followed
+    pub hops: usize,
+}
+
+/// Why a short link could not be resolved.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ShortLinkFailure {
+    /// The redirect chain was longer than the configured hop limit.
+    #[error("gave up after {0} redirects")]
+    TooManyHops(usize),
+    /// The redirect chain came back to a URL it had already visited.
+    #[error("redirect loop at {0}")]
+    RedirectLoop(String),
+    /// A redirect pointed at something that is not a URL.
+    #[error("invalid redirect target '{0}'")]
+    InvalidRedirect(String),
+    /// The short link did not redirect anywhere.
+    #[error("the short link does not redirect")]
+    NoRedirect,
+}
+
+/// Returns `true` if `url` is on the `lnkd.in` short link host.
+pub(crate) fn is_short_link(url: &Url) -> bool {
+    url.domain().is_some_and(|domain| {
+        let domain = domain.trim_end_matches('.');
+        domain.eq_ignore_ascii_case(SHORT_LINK_HOST)
+            || domain.eq_ignore_ascii_case(&format!("www.{SHORT_LINK_HOST}"))
+    })
+}
+
+/// Follows the redirect chain of `url` until it reaches a `LinkedIn` host.
+pub(crate) fn resolve_short_link<T: Transport>(
+    transport: &T,
+    url: &str,
+    domain_policy: &DomainPolicy,
+    max_hops: usize,
+) -> Result<ShortLink, LinkedInUrlError> {
+    let mut chain = RedirectChain::new(url, domain_policy, max_hops)?;
+    loop {
+        let response = transport.fetch(&chain.request())?;
+        if let Some(link) = chain.advance(&response)? {
+            return Ok(link);
+        }
+    }
+}
+
+/// Async counterpart of [`resolve_short_link`].
+pub(crate) async fn resolve_short_link_async<T: AsyncTransport>(
+    transport: &T,
+    url: &str,
+    domain_policy: &DomainPolicy,
+    max_hops: usize,
+) -> Result<ShortLink, LinkedInUrlError> {
+    let mut chain = RedirectChain::new(url, domain_policy, max_hops)?;
+    loop {
+        let response = transport.fetch(&chain.request()).await?;
+        if let Some(link) = chain.advance(&response)? {
+            return Ok(link);
+        }
+    }
+}
+
+/// Progress through a redirect chain, independent of how requests are sent.
+struct RedirectChain<'a> {
+    start: String,
+    visited: Vec<Url>,
+    domain_policy: &'a DomainPolicy,
+    max_hops: usize,
+}
+
+impl<'a> RedirectChain<'a> {
+    fn new(
+        url: &str,
+        domain_policy: &'a DomainPolicy,
+        max_hops: usize,
+    ) -> Result<Self, LinkedInUrlError> {
+        let start = Url::parse(url).map_err(|e| LinkedInUrlError::InvalidUrl(e.to_string()))?;
+        Ok(Self {
+            start: url.to_string(),
+            visited: vec![start],
+            domain_policy,
+            max_hops,
+        })
+    }
+
+    fn current(&self) -> &Url {
+        self.visited
+            .last()
+            .expect("the chain starts with the short link")
+    }
+
+    fn hops(&self) -> usize {
+        self.visited.len() - 1
+    }
+
+    fn request(&self) -> HttpRequest {
+        HttpRequest::get(self.current().as_str()).without_redirects()
+    }
+
+    /// Records `response` and returns the resolved link once the chain has ended.
+    fn advance(&mut self, response: &HttpResponse) -> Result<Option<ShortLink>, LinkedInUrlError> {
+        let next = match response.header("location") {
+            Some(location) if (300..400).contains(&response.status) => Some(location),
+            // The transport followed the redirects itself
+            _ if response.final_url != self.current().as_str() => Some(response.final_url.as_str()),
+            _ => None,
+        };
+
+        let Some(next) = next else {
+            if self.hops() == 0 {
+                return Err(LinkedInUrlError::UnresolvedShortLink(
+                    ShortLinkFailure::NoRedirect,
+                ));
+            }
+            return Ok(Some(self.finish()));
+        };
+
+        let next = self.current().join(next).map_err(|_| {
+            LinkedInUrlError::UnresolvedShortLink(ShortLinkFailure::InvalidRedirect(
+                next.to_string(),
+            ))
+        })?;
+        if self.visited.contains(&next) {
+            return Err(LinkedInUrlError::UnresolvedShortLink(
+                ShortLinkFailure::RedirectLoop(next.into()),
+            ));
+        }
+        if self.hops() == self.max_hops {
+            return Err(LinkedInUrlError::UnresolvedShortLink(
+                ShortLinkFailure::TooManyHops(self.max_hops),
+            ));
+        }
+
+        let reached_linkedin = self.domain_policy.url_host_variant(&next).is_some();
+        self.visited.push(next);
+        Ok(reached_linkedin.then(|| self.finish()))
+    }
+
+    fn finish(&self) -> ShortLink {
+        ShortLink {
+            url: self.start.clone(),
+            resolved_url: self.current().to_string(),
+            hops: self.hops(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHORT_URL: &str = "https://lnkd.in/abc123";
+
+    fn redirect(from: &str, to: &str) -> HttpResponse {
+        HttpResponse::new(301, from).with_header("Location", to)
+    }
+
+    #[test]
+    fn test_short_link_hosts() {
+        assert!(is_short_link(&Url::parse("https://lnkd.in/abc").unwrap()));
+        assert!(is_short_link(&Url::parse("http://LNKD.IN/abc").unwrap()));
+        assert!(!is_short_link(
+            &Url::parse("https://lnkd.in.evil.io/abc").unwrap()
+        ));
+        assert!(!is_short_link(
+            &Url::parse("https://linkedin.com/in/abc").unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_follows_chain_to_linkedin() {
+        let policy = DomainPolicy::new();
+        let mut chain = RedirectChain::new(SHORT_URL, &policy, 5).unwrap();
+
+        let hop = chain.advance(&redirect(SHORT_URL, "https://lnkd.in/def456"));
+        assert!(matches!(hop, Ok(None)));
+        assert_eq!(chain.request().url, "https://lnkd.in/def456");
+        assert!(!chain.request().follow_redirects);
+
+        let link = chain
+            .advance(&redirect(
+                "https://lnkd.in/def456",
+                "https://www.linkedin.com/in/johndoe",
+            ))
+            .unwrap()
+            .expect("a LinkedIn host ends the chain");
+        assert_eq!(link.url, SHORT_URL);
+        assert_eq!(link.resolved_url, "https://www.linkedin.com/in/johndoe");
+        assert_eq!(link.hops, 2);
+    }
+
+    #[test]
+    fn test_followed_redirects_resolve_in_one_step() {
+        let policy = DomainPolicy::new();
+        let mut chain = RedirectChain::new(SHORT_URL, &policy, 5).unwrap();
+
+        let link = chain
+            .advance(&HttpResponse::new(
+                200,
+                "https://www.linkedin.com/in/johndoe",
+            ))
+            .unwrap()
+            .expect("the final URL is on LinkedIn");
+        assert_eq!(link.resolved_url, "https://www.linkedin.com/in/johndoe");
+    }
+
+    #[test]
+    fn test_chain_failures() {
+        let policy = DomainPolicy::new();
+
+        let mut chain = RedirectChain::new(SHORT_URL, &policy, 5).unwrap();
+        assert!(matches!(
+            chain.advance(&HttpResponse::new(200, SHORT_URL)),
+            Err(LinkedInUrlError::UnresolvedShortLink(
+                ShortLinkFailure::NoRedirect
+            ))
+        ));
+
+        let mut chain = RedirectChain::new(SHORT_URL, &policy, 5).unwrap();
+        chain
+            .advance(&redirect(SHORT_URL, "https://lnkd.in/def456"))
+            .unwrap();
+        assert!(matches!(
+            chain.advance(&redirect("https://lnkd.in/def456", SHORT_URL)),
+            Err(LinkedInUrlError::UnresolvedShortLink(
+                ShortLinkFailure::RedirectLoop(_)
+            ))
+        ));
+
+        let mut chain = RedirectChain::new(SHORT_URL, &policy, 1).unwrap();
+        chain
+            .advance(&redirect(SHORT_URL, "https://lnkd.in/def456"))
+            .unwrap();
+        assert!(matches!(
+            chain.advance(&redirect(
+                "https://lnkd.in/def456",
+                "https://lnkd.in/ghi789"
+            )),
+            Err(LinkedInUrlError::UnresolvedShortLink(
+                ShortLinkFailure::TooManyHops(1)
+            ))
+        ));
+    }
+}
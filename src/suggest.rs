@@ -0,0 +1,267 @@
+//! Candidate profile URLs generated from a person's name.
+
+use crate::identity::words;
+use crate::profile_url::{CANONICAL_PREFIX, check_username};
+use crate::{AIDecision, AIValidationResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Candidates generated by default.
+const DEFAULT_MAX_CANDIDATES: usize = 20;
+
+/// Numeric suffixes tried by default, as in `jane-smith-1` to `jane-smith-3`.
+const DEFAULT_NUMERIC_SUFFIXES: u32 = 3;
+
+/// How candidate profile URLs are generated and checked.
+///
+/// By default up to 20 candidates are generated and none is validated, so no request is
+/// made. A validation budget checks that many of the highest ranked candidates with the
+/// validator, one request each.
+///
+/// # Example
+///
+/// ```
+/// use credify::SuggestOptions;
+///
+/// let options = SuggestOptions::new()
+///     .middle_name("Marie")
+///     .numeric_suffixes(5)
+///     .validation_budget(4);
+/// assert_eq!(options.max_validations(), 4);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuggestOptions {
+    middle_name: Option<String>,
+    max_candidates: usize,
+    numeric_suffixes: u32,
+    validation_budget: usize,
+}
+
+impl Default for SuggestOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SuggestOptions {
+    /// Creates the default options: up to 20 candidates with the numeric suffixes 1 to 3,
+    /// none of them validated.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            middle_name: None,
+            max_candidates: DEFAULT_MAX_CANDIDATES,
+            numeric_suffixes: DEFAULT_NUMERIC_SUFFIXES,
+            validation_budget: 0,
+        }
+    }
+
+    /// Adds candidates with the middle name and its initial, such as `jane-m-smith`.
+    #[must_use]
+    pub fn middle_name(mut self, middle_name: impl Into<String>) -> Self {
+        self.middle_name = Some(middle_name.into());
+        self
+    }
+
+    /// Sets how many candidates are returned at most.
+    #[must_use]
+    pub const fn max_candidates(mut self, max_candidates: usize) -> Self {
+        self.max_candidates = max_candidates;
+        self
+    }
+
+    /// Sets the highest numeric suffix tried, as in `jane-smith-2` and `janesmith2`.
+    /// Zero leaves numeric suffixes out.
+    #[must_use]
+    pub const fn numeric_suffixes(mut self, max_suffix: u32) -> Self {
+        self.numeric_suffixes = max_suffix;
+        self
+    }
+
+    /// Validates the `budget` highest ranked candidates, one request each.
+    #[must_use]
+    pub const fn validation_budget(mut self, budget: usize) -> Self {
+        self.validation_budget = budget;
+        self
+    }
+
+    /// Returns how many candidates are validated.
+    #[must_use]
+    pub const fn max_validations(&self) -> usize {
+        self.validation_budget
+    }
+}
+
+/// A profile URL a person may have, generated from their name.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfileSuggestion {
+    /// The vanity name, such as `jane-smith`
+    pub username: String,
+    /// The canonical profile URL for the vanity name
+    pub url: String,
+    /// How the vanity name was built from the name, such as `first-last` or `flast`
+    pub pattern: String,
+    /// The validation of the URL, if it was within the validation budget
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub validation: Option<AIValidationResult>,
+}
+
+impl ProfileSuggestion {
+    /// Returns whether `LinkedIn` has a profile at this URL.
+    ///
+    /// `None` if the URL was not validated or `LinkedIn` did not answer conclusively.
+    /// Profiles behind the authwall count as existing, like in
+    /// [`ai_validate`](crate::ai_validate).
+    #[must_use]
+    pub fn exists(&self) -> Option<bool> {
+        let validation = self.validation.as_ref()?;
+        match validation.decision {
+            AIDecision::Accept if validation.metadata.http_status.is_some() => Some(true),
+            AIDecision::Reject
+                if validation.metadata.error_type.as_deref() == Some("NOT_FOUND") =>
+            {
+                Some(false)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Generates the candidate profile URLs for `first` and `last`, most likely first.
+///
+/// Returns no candidate when either name has no letters or digits.
+pub(crate) fn candidates(
+    first: &str,
+    last: &str,
+    options: &SuggestOptions,
+) -> Vec<ProfileSuggestion> {
+    let (first_words, last_words) = (words(first), words(last));
+    let middle_words = words(options.middle_name.as_deref().unwrap_or_default());
+    if first_words.is_empty() || last_words.is_empty() {
+        return Vec::new();
+    }
+
+    let mut names = Vec::new();
+    let (f, l) = (first_words.join("-"), last_words.join("-"));
+    let (fc, lc) = (first_words.concat(), last_words.concat());
+    let fi = initial(&first_words);
+    names.push((format!("{f}-{l}"), "first-last"));
+    names.push((format!("{fc}{lc}"), "firstlast"));
+    names.push((format!("{fi}{lc}"), "flast"));
+    if !middle_words.is_empty() {
+        let (m, mc) = (middle_words.join("-"), middle_words.concat());
+        let mi = initial(&middle_words);
+        names.push((format!("{f}-{mi}-{l}"), "first-m-last"));
+        names.push((format!("{fc}{mi}{lc}"), "firstmlast"));
+        names.push((format!("{f}-{m}-{l}"), "first-middle-last"));
+        names.push((format!("{fc}{mc}{lc}"), "firstmiddlelast"));
+    }
+    names.push((format!("{l}-{f}"), "last-first"));
+    names.push((format!("{lc}{fc}"), "lastfirst"));
+    names.push((format!("{lc}{fi}"), "lastf"));
+
+    // Vanity names may keep their accents, but are usually typed without them
+    let (first_accented, last_accented) = (lowercase_words(first), lowercase_words(last));
+    if first_accented != first_words || last_accented != last_words {
+        let (f, l) = (first_accented.join("-"), last_accented.join("-"));
+        names.push((format!("{f}-{l}"), "first-last with accents"));
+        names.push((
+            format!("{}{}", first_accented.concat(), last_accented.concat()),
+            "firstlast with accents",
+        ));
+    }
+
+    for suffix in 1..=options.numeric_suffixes {
+        names.push((format!("{f}-{l}-{suffix}"), "first-last-N"));
+        names.push((format!("{fc}{lc}{suffix}"), "firstlastN"));
+    }
+
+    let mut seen = HashSet::new();
+    names
+        .into_iter()
+        .filter(|(username, _)| check_username(username).is_ok() && seen.insert(username.clone()))
+        .take(options.max_candidates)
+        .map(|(username, pattern)| ProfileSuggestion {
+            url: format!("{CANONICAL_PREFIX}{username}"),
+            username,
+            pattern: pattern.to_string(),
+            validation: None,
+        })
+        .collect()
+}
+
+/// Returns the first letter of the first word.
+fn initial(words: &[String]) -> String {
+    words
+        .first()
+        .and_then(|word| word.chars().next())
+        .map(String::from)
+        .unwrap_or_default()
+}
+
+/// Splits text into lowercase words, keeping accents.
+fn lowercase_words(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usernames(first: &str, last: &str, options: &SuggestOptions) -> Vec<String> {
+        candidates(first, last, options)
+            .into_iter()
+            .map(|suggestion| suggestion.username)
+            .collect()
+    }
+
+    #[test]
+    fn test_candidates_are_ranked() {
+        let options = SuggestOptions::new().numeric_suffixes(1);
+        assert_eq!(
+            usernames("Jane", "Smith", &options),
+            [
+                "jane-smith",
+                "janesmith",
+                "jsmith",
+                "smith-jane",
+                "smithjane",
+                "smithj",
+                "jane-smith-1",
+                "janesmith1"
+            ]
+        );
+
+        let suggestion = &candidates("Jane", "Smith", &options)[2];
+        assert_eq!(suggestion.url, "https://www.linkedin.com/in/jsmith");
+        assert_eq!(suggestion.pattern, "flast");
+        assert_eq!(suggestion.exists(), None);
+    }
+
+    #[test]
+    fn test_middle_names_and_accents() {
+        let options = SuggestOptions::new()
+            .middle_name("Marie")
+            .numeric_suffixes(0)
+            .max_candidates(6);
+        assert_eq!(
+            usernames("José", "van der Berg", &options),
+            [
+                "jose-van-der-berg",
+                "josevanderberg",
+                "jvanderberg",
+                "jose-m-van-der-berg",
+                "josemvanderberg",
+                "jose-marie-van-der-berg"
+            ]
+        );
+
+        let accented = usernames("José", "Müller", &SuggestOptions::new().numeric_suffixes(0));
+        assert!(accented.contains(&"josé-müller".to_string()));
+        assert!(usernames("", "Smith", &options).is_empty());
+    }
+}
@@ -0,0 +1,303 @@
+//! Public details read from a served profile page.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// `<meta>` tags.
+static META_TAG: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?is)<meta\b[^>]*>").expect("valid regex"));
+
+/// Attributes of an HTML tag, quoted with either kind of quote.
+static ATTRIBUTE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?is)([a-z][a-z0-9:_-]*)\s*=\s*(?:"([^"]*)"|'([^']*)')"#).expect("valid regex")
+});
+
+/// JSON-LD `<script>` blocks.
+static JSON_LD: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?is)<script\b[^>]*type\s*=\s*["']application/ld\+json["'][^>]*>(.*?)</script>"#)
+        .expect("valid regex")
+});
+
+/// The document `<title>`.
+static TITLE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?is)<title\b[^>]*>(.*?)</title>").expect("valid regex"));
+
+/// Numeric character references such as `&#39;` and `&#x27;`.
+static NUMERIC_ENTITY: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"&#(x[0-9a-fA-F]+|[0-9]+);").expect("valid regex"));
+
+/// Suffix `LinkedIn` appends to page titles.
+const TITLE_SUFFIX: &str = "| LinkedIn";
+
+/// What a public `LinkedIn` profile page tells about the person.
+///
+/// Filled from the page's JSON-LD `Person` block first, then from its `og:` meta tags and
+/// title. Fields the page does not expose are `None`, which is common when `LinkedIn` serves
+/// a reduced page to anonymous visitors.
+///
+/// # Example
+///
+/// ```
+/// use credify::parse_profile_summary;
+///
+/// let html = r#"<title>Jane Doe - Staff Engineer - Acme | LinkedIn</title>
+///     <meta property="og:image" content="https://media.licdn.com/jane.jpg">
+///     <meta name="description" content="Experience: Acme · Location: Berlin">"#;
+/// let summary = parse_profile_summary(html).expect("the page names the person");
+///
+/// assert_eq!(summary.display_name.as_deref(), Some("Jane Doe"));
+/// assert_eq!(summary.headline.as_deref(), Some("Staff Engineer - Acme"));
+/// assert_eq!(summary.location.as_deref(), Some("Berlin"));
+/// assert_eq!(summary.photo_url.as_deref(), Some("https://media.licdn.com/jane.jpg"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicProfileSummary {
+    /// The person's name
+    pub display_name: Option<String>,
+    /// The headline shown under the name, usually a job title
+    pub headline: Option<String>,
+    /// Where the person is based
+    pub location: Option<String>,
+    /// URL of the profile photo
+    pub photo_url: Option<String>,
+    /// Every `og:` meta tag of the page, keyed by property such as `og:title`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub og_tags: BTreeMap<String, String>,
+    /// The JSON-LD `Person` block, as published
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub json_ld: Option<Value>,
+}
+
+impl PublicProfileSummary {
+    /// Returns `true` if the page exposed nothing about the person.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+/// Reads the public details of a `LinkedIn` profile page from its HTML.
+///
+/// Returns `None` when the page exposes none of them, such as an authwall or an error page.
+/// No network request is made; validators set up with
+/// [`fetch_profile_summary`](crate::LinkedInValidatorBuilder::fetch_profile_summary) call
+/// this on the profile pages they fetch.
+#[must_use]
+pub fn parse_profile_summary(html: &str) -> Option<PublicProfileSummary> {
+    let meta = meta_tags(html);
+    let og_tags: BTreeMap<String, String> = meta
+        .iter()
+        .filter(|(key, _)| key.starts_with("og:"))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect();
+    let person = json_ld_person(html);
+
+    let title = person_title(og_tags.get("og:title").map(String::as_str)).or_else(|| {
+        person_title(
+            TITLE
+                .captures(html)
+                .map(|c| decode_entities(&c[1]))
+                .as_deref(),
+        )
+    });
+    let description = meta
+        .get("og:description")
+        .or_else(|| meta.get("description"));
+
+    let summary = PublicProfileSummary {
+        display_name: person
+            .as_ref()
+            .and_then(|person| text(person.get("name")))
+            .or_else(|| title.as_ref().map(|(name, _)| name.clone())),
+        headline: person
+            .as_ref()
+            .and_then(|person| text(person.get("jobTitle")))
+            .or_else(|| title.and_then(|(_, headline)| headline)),
+        location: person
+            .as_ref()
+            .and_then(json_ld_location)
+            .or_else(|| description.and_then(|d| labelled(d, "Location:"))),
+        photo_url: person
+            .as_ref()
+            .and_then(|person| image_url(person.get("image")?))
+            .or_else(|| og_tags.get("og:image").cloned()),
+        og_tags,
+        json_ld: person,
+    };
+    (!summary.is_empty()).then_some(summary)
+}
+
+/// Collects `<meta>` tags by their `property` or `name`, keeping the first of each.
+fn meta_tags(html: &str) -> BTreeMap<String, String> {
+    let mut tags = BTreeMap::new();
+    for tag in META_TAG.find_iter(html) {
+        let mut key = None;
+        let mut content = None;
+        for attribute in ATTRIBUTE.captures_iter(tag.as_str()) {
+            let value = attribute
+                .get(2)
+                .or_else(|| attribute.get(3))
+                .map_or("", |m| m.as_str());
+            match attribute[1].to_ascii_lowercase().as_str() {
+                "property" | "name" => key = Some(value.to_ascii_lowercase()),
+                "content" => content = Some(decode_entities(value)),
+                _ => {}
+            }
+        }
+        if let (Some(key), Some(content)) = (key, content) {
+            tags.entry(key).or_insert(content);
+        }
+    }
+    tags
+}
+
+/// Finds the first JSON-LD object typed `Person`, including inside `@graph` lists.
+fn json_ld_person(html: &str) -> Option<Value> {
+    JSON_LD
+        .captures_iter(html)
+        .filter_map(|block| serde_json::from_str::<Value>(block[1].trim()).ok())
+        .find_map(find_person)
+}
+
+fn find_person(value: Value) -> Option<Value> {
+    match value {
+        Value::Array(items) => items.into_iter().find_map(find_person),
+        Value::Object(mut object) => {
+            if let Some(graph) = object.remove("@graph") {
+                return find_person(graph);
+            }
+            let is_person = match object.get("@type") {
+                Some(Value::String(kind)) => kind == "Person",
+                Some(Value::Array(kinds)) => kinds.iter().any(|kind| kind == "Person"),
+                _ => false,
+            };
+            is_person.then_some(Value::Object(object))
+        }
+        _ => None,
+    }
+}
+
+/// Splits a `Name - Headline | LinkedIn` title into the name and the headline.
+fn person_title(title: Option<&str>) -> Option<(String, Option<String>)> {
+    let title = title?.trim();
+    let title = title.strip_suffix(TITLE_SUFFIX).unwrap_or(title).trim();
+    if title.is_empty() || title.eq_ignore_ascii_case("LinkedIn") {
+        return None;
+    }
+    let (name, headline) = match title.split_once(" - ") {
+        Some((name, headline)) => (name.trim(), Some(headline.trim().to_string())),
+        None => (title, None),
+    };
+    Some((name.to_string(), headline.filter(|h| !h.is_empty())))
+}
+
+/// Returns a JSON-LD value as text, taking the first entry of a list.
+fn text(value: Option<&Value>) -> Option<String> {
+    match value? {
+        Value::String(text) => Some(decode_entities(text.trim())).filter(|t| !t.is_empty()),
+        Value::Array(items) => items.iter().find_map(|item| text(Some(item))),
+        _ => None,
+    }
+}
+
+/// Reads `address` as a `PostalAddress`, joining locality, region and country.
+fn json_ld_location(person: &Value) -> Option<String> {
+    let address = person.get("address")?;
+    if let Some(address) = text(Some(address)) {
+        return Some(address);
+    }
+    let parts: Vec<String> = ["addressLocality", "addressRegion", "addressCountry"]
+        .iter()
+        .filter_map(|field| text(address.get(field)))
+        .collect();
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+/// Reads an `image` given as a URL or an `ImageObject`.
+fn image_url(image: &Value) -> Option<String> {
+    match image {
+        Value::Array(items) => items.iter().find_map(image_url),
+        Value::Object(_) => text(image.get("contentUrl")).or_else(|| text(image.get("url"))),
+        _ => text(Some(image)),
+    }
+}
+
+/// Returns the text after `label` in a `Label: value · Label: value` description.
+fn labelled(description: &str, label: &str) -> Option<String> {
+    let (_, rest) = description.split_once(label)?;
+    let value = rest.split('·').next()?.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// Decodes the character references `LinkedIn` uses in attribute values and titles.
+fn decode_entities(text: &str) -> String {
+    let text = NUMERIC_ENTITY.replace_all(text, |c: &regex::Captures<'_>| {
+        let code = &c[1];
+        let code = code.strip_prefix('x').map_or_else(
+            || code.parse().ok(),
+            |hex| u32::from_str_radix(hex, 16).ok(),
+        );
+        code.and_then(char::from_u32)
+            .map_or_else(|| c[0].to_string(), String::from)
+    });
+    text.replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_ld_person_comes_first() {
+        let html = r#"<head>
+            <title>Jane Doe - Engineer | LinkedIn</title>
+            <meta property="og:title" content="Jane Doe - Engineer | LinkedIn" />
+            <meta property="og:image" content="https://media.licdn.com/og.jpg" />
+            <script type="application/ld+json">{"@context": "http://schema.org", "@graph": [
+                {"@type": "WebPage", "name": "Profile"},
+                {"@type": "Person", "name": "Jane Doe", "jobTitle": ["Staff Engineer"],
+                 "address": {"@type": "PostalAddress", "addressLocality": "Berlin",
+                             "addressCountry": "DE"},
+                 "image": {"@type": "ImageObject", "contentUrl": "https://media.licdn.com/ld.jpg"}}
+            ]}</script>
+        </head>"#;
+        let summary = parse_profile_summary(html).expect("summary is found");
+
+        assert_eq!(summary.display_name.as_deref(), Some("Jane Doe"));
+        assert_eq!(summary.headline.as_deref(), Some("Staff Engineer"));
+        assert_eq!(summary.location.as_deref(), Some("Berlin, DE"));
+        assert_eq!(
+            summary.photo_url.as_deref(),
+            Some("https://media.licdn.com/ld.jpg")
+        );
+        assert_eq!(summary.og_tags.len(), 2);
+        assert!(summary.json_ld.is_some());
+    }
+
+    #[test]
+    fn test_entities_are_decoded() {
+        let html = "<meta property='og:title' content='Se&#225;n O&#39;Brien &amp; co | LinkedIn'>";
+        let summary = parse_profile_summary(html).expect("summary is found");
+        assert_eq!(
+            summary.display_name.as_deref(),
+            Some("Se\u{e1}n O'Brien & co")
+        );
+    }
+
+    #[test]
+    fn test_pages_without_details() {
+        assert_eq!(parse_profile_summary("<title>LinkedIn</title>"), None);
+        assert_eq!(
+            parse_profile_summary("<a href=\"/authwall\">Sign in</a>"),
+            None
+        );
+    }
+}
@@ -0,0 +1,354 @@
+//! Adaptive backoff and circuit breaking when `LinkedIn` throttles requests.
+
+use crate::{DetectionRules, HttpResponse, ProfileStatus};
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// How a validator reacts when `LinkedIn` starts refusing requests.
+///
+/// A response counts as a block when it is rate limited (999 after the cookie retry, or
+/// 429) or forbidden (403). Blocked requests are retried after an exponential backoff
+/// with jitter, waiting at least as long as `Retry-After` asks. A `Retry-After` longer
+/// than the backoff cap is not waited for: the response is reported as rate limited, with
+/// the requested delay, for the caller to schedule. After `threshold`
+/// consecutive blocks the circuit breaker opens and every request sent through the
+/// validator, and its clones, waits for the cooldown. A single block after the cooldown
+/// reopens it; any unblocked response closes it.
+///
+/// Authwall pages are not counted by default, because `LinkedIn` serves them for most
+/// existing profiles; enable [`count_authwall`](Self::count_authwall) when a streak of
+/// them means the client is being throttled.
+///
+/// # Example
+///
+/// ```
+/// use credify::ThrottlePolicy;
+/// use std::time::Duration;
+///
+/// let policy = ThrottlePolicy::new()
+///     .max_retries(4)
+///     .backoff(Duration::from_secs(2), Duration::from_secs(120))
+///     .circuit_breaker(10, Duration::from_secs(600));
+/// assert_eq!(policy.retries(), 4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThrottlePolicy {
+    max_retries: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: f64,
+    threshold: u32,
+    cooldown: Duration,
+    count_authwall: bool,
+}
+
+impl Default for ThrottlePolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ThrottlePolicy {
+    /// Creates the default policy: 3 retries backing off from 1s up to 60s with 50%
+    /// jitter, and a 5 minute pause after 5 consecutive blocks.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(60),
+            jitter: 0.5,
+            threshold: 5,
+            cooldown: Duration::from_secs(300),
+            count_authwall: false,
+        }
+    }
+
+    /// Sets how many times a blocked request is retried.
+    #[must_use]
+    pub const fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the delay before the first retry and the cap the doubling delays stop at.
+    ///
+    /// The cap also bounds how long a `Retry-After` header can make a retry wait.
+    #[must_use]
+    pub const fn backoff(mut self, base_delay: Duration, max_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Sets the share of each delay, between 0 and 1, that is randomly taken off so
+    /// concurrent requests do not retry in lockstep.
+    #[must_use]
+    pub const fn jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Opens the circuit breaker after `threshold` consecutive blocks, pausing every
+    /// request for `cooldown`. A threshold of 0 disables the breaker.
+    #[must_use]
+    pub const fn circuit_breaker(mut self, threshold: u32, cooldown: Duration) -> Self {
+        self.threshold = threshold;
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// Sets whether authwall pages count as blocks.
+    #[must_use]
+    pub const fn count_authwall(mut self, count: bool) -> Self {
+        self.count_authwall = count;
+        self
+    }
+
+    /// Returns how many times a blocked request is retried.
+    #[must_use]
+    pub const fn retries(&self) -> u32 {
+        self.max_retries
+    }
+
+    /// Returns the delay before retry number `attempt` (0-based), before `Retry-After`.
+    fn delay(&self, attempt: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_delay);
+        delay.mul_f64(self.jitter.mul_add(-random_fraction(), 1.0))
+    }
+}
+
+/// What a validator has seen of `LinkedIn`'s throttling so far.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThrottleState {
+    /// Blocks received since the last unblocked response
+    pub consecutive_blocks: u32,
+    /// Blocks received in total
+    pub total_blocks: u64,
+    /// Requests retried after a block
+    pub retries: u64,
+    /// Time left before the open circuit breaker lets requests through
+    pub cooldown_remaining: Option<Duration>,
+    /// The last `Retry-After` delay `LinkedIn` asked for
+    pub last_retry_after: Option<Duration>,
+}
+
+impl ThrottleState {
+    /// Returns `true` while the circuit breaker pauses requests.
+    #[must_use]
+    pub const fn is_circuit_open(&self) -> bool {
+        self.cooldown_remaining.is_some()
+    }
+}
+
+/// Throttling state shared by a validator and its clones.
+#[derive(Debug)]
+pub(crate) struct Throttle {
+    policy: ThrottlePolicy,
+    state: Mutex<Counters>,
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    consecutive_blocks: u32,
+    total_blocks: u64,
+    retries: u64,
+    open_until: Option<Instant>,
+    last_retry_after: Option<Duration>,
+}
+
+impl Throttle {
+    pub(crate) fn new(policy: ThrottlePolicy) -> Self {
+        Self {
+            policy,
+            state: Mutex::new(Counters::default()),
+        }
+    }
+
+    /// Returns how long requests must wait for the open circuit breaker.
+    pub(crate) fn pause(&self) -> Option<Duration> {
+        let state = self.lock();
+        state
+            .open_until
+            .and_then(|until| until.checked_duration_since(Instant::now()))
+            .filter(|remaining| !remaining.is_zero())
+    }
+
+    /// Records the response to attempt number `attempt` (0-based).
+    ///
+    /// Returns how long to wait before retrying when the response is a block and
+    /// retries remain.
+    pub(crate) fn record(
+        &self,
+        response: &HttpResponse,
+        rules: &DetectionRules,
+        attempt: u32,
+    ) -> Option<Duration> {
+        let status = rules.classify(
+            response.status,
+            &response.final_url,
+            &response.headers,
+            &response.body,
+        );
+        let retry_after = match status {
+            ProfileStatus::RateLimited { retry_after } => retry_after,
+            ProfileStatus::Blocked => None,
+            ProfileStatus::LikelyExists { .. } if self.policy.count_authwall => None,
+            _ => {
+                let mut state = self.lock();
+                state.consecutive_blocks = 0;
+                state.open_until = None;
+                return None;
+            }
+        };
+
+        let mut state = self.lock();
+        state.consecutive_blocks += 1;
+        state.total_blocks += 1;
+        if retry_after.is_some() {
+            state.last_retry_after = retry_after;
+        }
+        if self.policy.threshold > 0 && state.consecutive_blocks >= self.policy.threshold {
+            state.open_until = Some(Instant::now() + self.policy.cooldown);
+        }
+        if attempt >= self.policy.max_retries
+            || retry_after.is_some_and(|retry_after| retry_after > self.policy.max_delay)
+        {
+            return None;
+        }
+        state.retries += 1;
+        let delay = self.policy.delay(attempt);
+        Some(retry_after.map_or(delay, |retry_after| delay.max(retry_after)))
+    }
+
+    /// Returns a snapshot of the counters.
+    pub(crate) fn state(&self) -> ThrottleState {
+        let cooldown_remaining = self.pause();
+        let state = self.lock();
+        ThrottleState {
+            consecutive_blocks: state.consecutive_blocks,
+            total_blocks: state.total_blocks,
+            retries: state.retries,
+            cooldown_remaining,
+            last_retry_after: state.last_retry_after,
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Counters> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Returns a random number in `[0, 1)` from the standard library's hasher seeds.
+fn random_fraction() -> f64 {
+    let bits = RandomState::new().build_hasher().finish() >> 11;
+    #[allow(clippy::cast_precision_loss)] // 53 bits fit an f64 mantissa exactly
+    let fraction = bits as f64 / (1u64 << 53) as f64;
+    fraction
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blocked() -> HttpResponse {
+        HttpResponse::new(999, "https://www.linkedin.com/in/johndoe")
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_the_cap() {
+        let policy = ThrottlePolicy::new()
+            .backoff(Duration::from_secs(1), Duration::from_secs(5))
+            .jitter(0.0);
+        assert_eq!(policy.delay(0), Duration::from_secs(1));
+        assert_eq!(policy.delay(2), Duration::from_secs(4));
+        assert_eq!(policy.delay(3), Duration::from_secs(5));
+        assert_eq!(policy.delay(40), Duration::from_secs(5));
+
+        let jittered = ThrottlePolicy::new().jitter(0.5).delay(0);
+        assert!(jittered > Duration::from_millis(499) && jittered <= Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_retry_after_is_honoured() {
+        let throttle = Throttle::new(ThrottlePolicy::new().jitter(0.0));
+        let response = blocked().with_header("Retry-After", "30");
+
+        assert_eq!(
+            throttle.record(&response, &DetectionRules::default(), 0),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            throttle.state().last_retry_after,
+            Some(Duration::from_secs(30))
+        );
+    }
+
+    #[test]
+    fn test_long_retry_after_is_not_waited_for() {
+        let throttle = Throttle::new(ThrottlePolicy::new());
+        let response = blocked().with_header("Retry-After", "86400");
+
+        assert_eq!(
+            throttle.record(&response, &DetectionRules::default(), 0),
+            None
+        );
+        let state = throttle.state();
+        assert_eq!(state.retries, 0);
+        assert_eq!(state.last_retry_after, Some(Duration::from_secs(86400)));
+    }
+
+    #[test]
+    fn test_circuit_breaker_opens_and_closes() {
+        let policy = ThrottlePolicy::new()
+            .max_retries(0)
+            .circuit_breaker(2, Duration::from_secs(60));
+        let throttle = Throttle::new(policy);
+
+        assert_eq!(
+            throttle.record(&blocked(), &DetectionRules::default(), 0),
+            None
+        );
+        assert!(throttle.pause().is_none());
+        assert_eq!(
+            throttle.record(&blocked(), &DetectionRules::default(), 0),
+            None
+        );
+        assert!(throttle.pause().is_some());
+
+        let state = throttle.state();
+        assert!(state.is_circuit_open());
+        assert_eq!((state.consecutive_blocks, state.total_blocks), (2, 2));
+
+        let ok = HttpResponse::new(200, "https://www.linkedin.com/in/johndoe");
+        assert_eq!(throttle.record(&ok, &DetectionRules::default(), 0), None);
+        assert_eq!(throttle.state().consecutive_blocks, 0);
+        assert!(!throttle.state().is_circuit_open());
+    }
+
+    #[test]
+    fn test_authwall_counts_only_when_enabled() {
+        let authwall = HttpResponse::new(200, "https://www.linkedin.com/in/johndoe")
+            .with_body("<a href=\"/authwall?trk=x\">Sign in</a>");
+
+        let throttle = Throttle::new(ThrottlePolicy::new());
+        assert_eq!(
+            throttle.record(&authwall, &DetectionRules::default(), 0),
+            None
+        );
+        assert_eq!(throttle.state().total_blocks, 0);
+
+        let throttle = Throttle::new(ThrottlePolicy::new().count_authwall(true).jitter(0.0));
+        assert_eq!(
+            throttle.record(&authwall, &DetectionRules::default(), 0),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(throttle.state().total_blocks, 1);
+    }
+}
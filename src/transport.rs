@@ -0,0 +1,365 @@
+//! Pluggable HTTP transport used by the validators.
+//!
+//! The validators never talk to `reqwest` directly. They issue an [`HttpRequest`]
+//! through a [`Transport`] (blocking) or [`AsyncTransport`] (async) and classify
+//! the resulting [`HttpResponse`]. This makes it possible to run the full
+//! validation pipeline against a local stand-in such as [`ScriptedTransport`].
+
+use crate::{LinkedInUrlError, LinkedInValidatorBuilder};
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// User agent sent by the default transports.
+pub(crate) const DEFAULT_USER_AGENT: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/126.0.0.0 Safari/537.36";
+
+/// Request timeout used by the default transports.
+pub(crate) const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// An outgoing HTTP `GET` request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    /// Absolute URL to fetch
+    pub url: String,
+    /// Extra headers sent with this request only
+    pub headers: Vec<(String, String)>,
+    /// Whether the transport should follow redirects (`true` by default)
+    pub follow_redirects: bool,
+}
+
+impl HttpRequest {
+    /// Creates a `GET` request for `url`.
+    #[must_use]
+    pub fn get(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            headers: Vec::new(),
+            follow_redirects: true,
+        }
+    }
+
+    /// Adds a header to the request.
+    #[must_use]
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Asks the transport to return redirect responses instead of following them.
+    ///
+    /// Transports that cannot do this may still follow redirects; callers must accept
+    /// either a 3xx response with a `Location` header or the final response.
+    #[must_use]
+    pub const fn without_redirects(mut self) -> Self {
+        self.follow_redirects = false;
+        self
+    }
+}
+
+/// The parts of an HTTP response the validators look at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    /// HTTP status code (`LinkedIn` also uses the non-standard 999)
+    pub status: u16,
+    /// URL of the response after any redirects were followed
+    pub final_url: String,
+    /// Response headers in the order they were received
+    pub headers: Vec<(String, String)>,
+    /// Response body decoded as text
+    pub body: String,
+}
+
+impl HttpResponse {
+    /// Creates an empty response with the given status and final URL.
+    #[must_use]
+    pub fn new(status: u16, final_url: impl Into<String>) -> Self {
+        Self {
+            status,
+            final_url: final_url.into(),
+            headers: Vec::new(),
+            body: String::new(),
+        }
+    }
+
+    /// Adds a response header.
+    #[must_use]
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the response body.
+    #[must_use]
+    pub fn with_body(mut self, body: impl Into<String>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Returns the first header named `name` (case-insensitive).
+    #[must_use]
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// A blocking HTTP transport.
+pub trait Transport {
+    /// Performs `request` and returns the response after following redirects.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request could not be completed.
+    fn fetch(&self, request: &HttpRequest) -> Result<HttpResponse, LinkedInUrlError>;
+}
+
+/// An async HTTP transport.
+pub trait AsyncTransport: Send + Sync {
+    /// Performs `request` and returns the response after following redirects.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request could not be completed.
+    fn fetch(
+        &self,
+        request: &HttpRequest,
+    ) -> impl Future<Output = Result<HttpResponse, LinkedInUrlError>> + Send;
+}
+
+/// Blocking transport backed by `reqwest`. This is the default for
+/// [`LinkedInValidator`](crate::LinkedInValidator).
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: reqwest::blocking::Client,
+    manual_redirect_client: Option<reqwest::blocking::Client>,
+}
+
+impl ReqwestTransport {
+    /// Creates a transport with the default [`LinkedInValidatorBuilder`] settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be built.
+    pub fn new() -> Result<Self, LinkedInUrlError> {
+        LinkedInValidatorBuilder::new().build_transport()
+    }
+
+    /// Wraps an already configured `reqwest` blocking client.
+    #[must_use]
+    pub const fn from_client(client: reqwest::blocking::Client) -> Self {
+        Self {
+            client,
+            manual_redirect_client: None,
+        }
+    }
+
+    /// Sets the client used for [`HttpRequest::without_redirects`] requests.
+    ///
+    /// It should be configured like the main client but with
+    /// `reqwest::redirect::Policy::none()`. Without it those requests follow redirects.
+    #[must_use]
+    pub fn with_manual_redirect_client(mut self, client: reqwest::blocking::Client) -> Self {
+        self.manual_redirect_client = Some(client);
+        self
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn fetch(&self, request: &HttpRequest) -> Result<HttpResponse, LinkedInUrlError> {
+        let client = match &self.manual_redirect_client {
+            Some(client) if !request.follow_redirects => client,
+            _ => &self.client,
+        };
+        let mut builder = client.get(&request.url);
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+
+        let response = builder.send()?;
+        let status = response.status().as_u16();
+        let final_url = response.url().to_string();
+        let headers = collect_headers(response.headers());
+        let body = response.text()?;
+
+        Ok(HttpResponse {
+            status,
+            final_url,
+            headers,
+            body,
+        })
+    }
+}
+
+/// Async transport backed by `reqwest`.
+#[derive(Debug, Clone)]
+pub struct AsyncReqwestTransport {
+    client: reqwest::Client,
+    manual_redirect_client: Option<reqwest::Client>,
+}
+
+impl AsyncReqwestTransport {
+    /// Creates a transport with the default [`LinkedInValidatorBuilder`] settings.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be built.
+    pub fn new() -> Result<Self, LinkedInUrlError> {
+        LinkedInValidatorBuilder::new().build_async_transport()
+    }
+
+    /// Wraps an already configured `reqwest` async client.
+    #[must_use]
+    pub const fn from_client(client: reqwest::Client) -> Self {
+        Self {
+            client,
+            manual_redirect_client: None,
+        }
+    }
+
+    /// Sets the client used for [`HttpRequest::without_redirects`] requests.
+    ///
+    /// See [`ReqwestTransport::with_manual_redirect_client`].
+    #[must_use]
+    pub fn with_manual_redirect_client(mut self, client: reqwest::Client) -> Self {
+        self.manual_redirect_client = Some(client);
+        self
+    }
+}
+
+impl AsyncTransport for AsyncReqwestTransport {
+    async fn fetch(&self, request: &HttpRequest) -> Result<HttpResponse, LinkedInUrlError> {
+        let client = match &self.manual_redirect_client {
+            Some(client) if !request.follow_redirects => client,
+            _ => &self.client,
+        };
+        let mut builder = client.get(&request.url);
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+
+        let response = builder.send().await?;
+        let status = response.status().as_u16();
+        let final_url = response.url().to_string();
+        let headers = collect_headers(response.headers());
+        let body = response.text().await?;
+
+        Ok(HttpResponse {
+            status,
+            final_url,
+            headers,
+            body,
+        })
+    }
+}
+
+fn collect_headers(headers: &reqwest::header::HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            (
+                name.as_str().to_string(),
+                value.to_str().unwrap_or_default().to_string(),
+            )
+        })
+        .collect()
+}
+
+/// In-memory transport that replays scripted responses.
+///
+/// Responses registered with [`respond_to`](Self::respond_to) are returned every
+/// time that exact URL is requested. Any other request consumes the next entry
+/// queued with [`enqueue`](Self::enqueue) or [`enqueue_error`](Self::enqueue_error).
+/// Every request is recorded and can be inspected with [`requests`](Self::requests).
+///
+/// # Example
+///
+/// ```
+/// use credify::{HttpResponse, LinkedInValidator, ScriptedTransport};
+///
+/// let transport = ScriptedTransport::new();
+/// transport.enqueue(HttpResponse::new(200, "https://www.linkedin.com/in/johndoe"));
+///
+/// let validator = LinkedInValidator::with_transport(transport);
+/// assert!(validator
+///     .is_valid_linkedin_profile_url("https://www.linkedin.com/in/johndoe")
+///     .is_ok());
+/// ```
+#[derive(Debug, Default)]
+pub struct ScriptedTransport {
+    routes: Mutex<HashMap<String, Result<HttpResponse, String>>>,
+    queue: Mutex<VecDeque<Result<HttpResponse, String>>>,
+    requests: Mutex<Vec<HttpRequest>>,
+}
+
+impl ScriptedTransport {
+    /// Creates a transport with no scripted responses.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Always answers requests for `url` with `response`.
+    pub fn respond_to(&self, url: impl Into<String>, response: HttpResponse) {
+        lock(&self.routes).insert(url.into(), Ok(response));
+    }
+
+    /// Always fails requests for `url` with a transport error.
+    pub fn fail_for(&self, url: impl Into<String>, message: impl Into<String>) {
+        lock(&self.routes).insert(url.into(), Err(message.into()));
+    }
+
+    /// Queues a response for the next unrouted request.
+    pub fn enqueue(&self, response: HttpResponse) {
+        lock(&self.queue).push_back(Ok(response));
+    }
+
+    /// Queues a transport error for the next unrouted request.
+    pub fn enqueue_error(&self, message: impl Into<String>) {
+        lock(&self.queue).push_back(Err(message.into()));
+    }
+
+    /// Returns every request received so far.
+    #[must_use]
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        lock(&self.requests).clone()
+    }
+
+    fn answer(&self, request: &HttpRequest) -> Result<HttpResponse, LinkedInUrlError> {
+        lock(&self.requests).push(request.clone());
+
+        let scripted = lock(&self.routes)
+            .get(&request.url)
+            .cloned()
+            .or_else(|| lock(&self.queue).pop_front());
+
+        match scripted {
+            Some(Ok(response)) => Ok(response),
+            Some(Err(message)) => Err(LinkedInUrlError::TransportError(message)),
+            None => Err(LinkedInUrlError::TransportError(format!(
+                "no scripted response for {}",
+                request.url
+            ))),
+        }
+    }
+}
+
+impl Transport for ScriptedTransport {
+    fn fetch(&self, request: &HttpRequest) -> Result<HttpResponse, LinkedInUrlError> {
+        self.answer(request)
+    }
+}
+
+impl AsyncTransport for ScriptedTransport {
+    async fn fetch(&self, request: &HttpRequest) -> Result<HttpResponse, LinkedInUrlError> {
+        self.answer(request)
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+}
@@ -0,0 +1,913 @@
+//! Deterministic validation tests driven by the scripted transport
+//!
+//! These tests exercise the real 999/authwall/404/redirect handling without
+//! touching the network.
+
+use credify::{
+    AIDecision, AsyncLinkedInValidator, BatchOptions, CachePolicy, DetectionRules, DomainPolicy,
+    EntityKind, FileCache, HostVariant, HttpResponse, LegacyFormat, LinkedInUrlError,
+    LinkedInValidator, MatchSignalKind, MatchVerdict, PersonHint, ProfileSection, ProfileStatus,
+    RetryBackoff, RetryPolicy, ScriptedTransport, ShortLinkFailure, SuggestOptions, ThrottlePolicy,
+    UrlRepair, validate_linkedin_url_with_transport,
+};
+use futures_util::StreamExt;
+use std::time::Duration;
+
+const PROFILE_URL: &str = "https://www.linkedin.com/in/johndoe";
+
+fn validator_with(responses: Vec<HttpResponse>) -> LinkedInValidator<ScriptedTransport> {
+    let transport = ScriptedTransport::new();
+    for response in responses {
+        transport.enqueue(response);
+    }
+    LinkedInValidator::with_transport(transport)
+}
+
+#[test]
+fn test_existing_profile() {
+    let validator = validator_with(vec![
+        HttpResponse::new(200, PROFILE_URL).with_body("<title>John Doe | LinkedIn</title>"),
+    ]);
+
+    assert!(matches!(
+        validator.is_valid_linkedin_profile_url(PROFILE_URL),
+        Ok(true)
+    ));
+    assert_eq!(validator.transport().requests().len(), 1);
+}
+
+#[test]
+fn test_redirect_to_404_page() {
+    let validator = validator_with(vec![HttpResponse::new(
+        200,
+        "https://www.linkedin.com/404/",
+    )]);
+
+    assert!(matches!(
+        validator.is_valid_linkedin_profile_url(PROFILE_URL),
+        Err(LinkedInUrlError::ProfileNotFound)
+    ));
+}
+
+#[test]
+fn test_not_found_body() {
+    let validator =
+        validator_with(vec![HttpResponse::new(200, PROFILE_URL).with_body(
+            "<h1>This page doesn&#39;t exist</h1><a>Go to your feed</a>",
+        )]);
+
+    assert!(matches!(
+        validator.is_valid_linkedin_profile_url(PROFILE_URL),
+        Err(LinkedInUrlError::ProfileNotFound)
+    ));
+}
+
+#[test]
+fn test_authwall_body() {
+    let validator =
+        validator_with(vec![HttpResponse::new(200, PROFILE_URL).with_body(
+            r#"<script>window.location = "/authwall?trk=foo";</script>"#,
+        )]);
+
+    assert!(matches!(
+        validator.is_valid_linkedin_profile_url(PROFILE_URL),
+        Err(LinkedInUrlError::AuthenticationRequired)
+    ));
+}
+
+#[test]
+fn test_999_retries_with_cookie() {
+    let validator = validator_with(vec![
+        HttpResponse::new(999, PROFILE_URL),
+        HttpResponse::new(200, PROFILE_URL).with_body("<title>John Doe | LinkedIn</title>"),
+    ]);
+
+    assert!(matches!(
+        validator.is_valid_linkedin_profile_url(PROFILE_URL),
+        Ok(true)
+    ));
+
+    let requests = validator.transport().requests();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[0].headers.is_empty());
+    assert_eq!(
+        requests[1].headers,
+        vec![("Cookie".to_string(), "sl=v=1&1".to_string())]
+    );
+}
+
+#[test]
+fn test_validate_outcome() {
+    let validator = validator_with(vec![
+        HttpResponse::new(200, PROFILE_URL).with_body("<title>John Doe | LinkedIn</title>"),
+    ]);
+
+    let outcome = validator
+        .validate("https://linkedin.com/in/johndoe/")
+        .expect("validation should succeed");
+    assert_eq!(outcome.status, ProfileStatus::Exists);
+    assert_eq!(outcome.username, "johndoe");
+    assert_eq!(outcome.canonical_url, PROFILE_URL);
+    assert_eq!(outcome.http_status, 200);
+    assert_eq!(outcome.final_url, PROFILE_URL);
+}
+
+#[test]
+fn test_rate_limited_is_not_an_error() {
+    let validator = validator_with(vec![
+        HttpResponse::new(999, PROFILE_URL),
+        HttpResponse::new(999, PROFILE_URL).with_header("Retry-After", "60"),
+    ]);
+
+    let outcome = validator
+        .validate(PROFILE_URL)
+        .expect("rate limiting is an outcome, not an error");
+    assert_eq!(
+        outcome.status,
+        ProfileStatus::RateLimited {
+            retry_after: Some(Duration::from_secs(60))
+        }
+    );
+    assert_eq!(outcome.http_status, 999);
+}
+
+#[test]
+fn test_rate_limited_ai_result_retries() {
+    let validator = validator_with(vec![
+        HttpResponse::new(429, PROFILE_URL),
+        HttpResponse::new(429, PROFILE_URL),
+    ]);
+
+    let result = validator.ai_validate(PROFILE_URL);
+    assert_eq!(result.decision, AIDecision::Retry);
+    assert_eq!(result.metadata.http_status, Some(429));
+    assert_eq!(result.metadata.error_type.as_deref(), Some("RATE_LIMITED"));
+
+    let report = validator.validate_for_llm(PROFILE_URL);
+    assert!(report.contains("VALIDATION_RESULT: INCONCLUSIVE"));
+    assert!(report.contains("ERROR_TYPE: RATE_LIMITED"));
+}
+
+#[test]
+fn test_country_host_variant_is_reported() {
+    let validator = validator_with(vec![
+        HttpResponse::new(200, PROFILE_URL),
+        HttpResponse::new(200, PROFILE_URL),
+    ]);
+
+    let outcome = validator
+        .validate("https://uk.linkedin.com/in/johndoe")
+        .expect("country hosts are accepted by default");
+    assert_eq!(outcome.host_variant, HostVariant::Country("uk".to_string()));
+    assert_eq!(outcome.canonical_url, PROFILE_URL);
+
+    let result = validator.ai_validate("https://m.linkedin.com/in/JohnDoe/?trk=share");
+    assert_eq!(result.metadata.host_variant, Some(HostVariant::Mobile));
+    assert_eq!(result.username.as_deref(), Some("JohnDoe"));
+    assert_eq!(result.canonical_url.as_deref(), Some(PROFILE_URL));
+}
+
+#[test]
+fn test_sub_page_checks_root_profile() {
+    let transport = ScriptedTransport::new();
+    transport.respond_to(PROFILE_URL, HttpResponse::new(200, PROFILE_URL));
+    let validator = LinkedInValidator::with_transport(transport);
+
+    let outcome = validator
+        .validate("https://www.linkedin.com/in/johndoe/details/experience/")
+        .expect("sub-pages are profile URLs");
+    assert_eq!(outcome.status, ProfileStatus::Exists);
+    assert_eq!(
+        outcome.section,
+        ProfileSection::Details("experience".to_string())
+    );
+
+    let outcome = validator
+        .validate("https://www.linkedin.com/in/johndoe/overlay/contact-info/")
+        .expect("sub-pages are profile URLs");
+    assert_eq!(outcome.section, ProfileSection::ContactInfo);
+
+    let requests = validator.transport().requests();
+    assert!(requests.iter().all(|request| request.url == PROFILE_URL));
+}
+
+#[test]
+fn test_unicode_username_in_ai_result() {
+    let validator = validator_with(vec![HttpResponse::new(
+        200,
+        "https://www.linkedin.com/in/%E5%BC%A0%E4%BC%9F-li",
+    )]);
+
+    let result = validator.ai_validate("https://www.linkedin.com/in/%E5%BC%A0%E4%BC%9F-Li");
+    assert!(result.is_valid);
+    assert_eq!(result.username.as_deref(), Some("张伟-Li"));
+    assert_eq!(
+        result.canonical_url.as_deref(),
+        Some("https://www.linkedin.com/in/张伟-li")
+    );
+
+    let result = validator.ai_validate("https://www.linkedin.com/in/ab");
+    assert_eq!(result.decision, AIDecision::Reject);
+    assert_eq!(
+        result.metadata.error_type.as_deref(),
+        Some("INVALID_USERNAME")
+    );
+    assert_eq!(validator.transport().requests().len(), 1);
+}
+
+#[test]
+fn test_strict_domain_policy() {
+    let validator = validator_with(vec![]).with_domain_policy(DomainPolicy::strict());
+
+    assert!(matches!(
+        validator.validate("https://fr.linkedin.com/in/johndoe"),
+        Err(LinkedInUrlError::NotLinkedInUrl)
+    ));
+    assert!(validator.transport().requests().is_empty());
+}
+
+#[test]
+fn test_legacy_url_resolves_to_profile() {
+    let legacy_url = "https://www.linkedin.com/pub/jane-doe/12/345/678";
+    let transport = ScriptedTransport::new();
+    transport.respond_to(
+        legacy_url,
+        HttpResponse::new(200, "https://www.linkedin.com/in/jane-doe-12345678"),
+    );
+    let validator = LinkedInValidator::with_transport(transport);
+
+    let outcome = validator
+        .validate("http://uk.linkedin.com/pub/jane-doe/12/345/678?trk=pub-pbmap")
+        .expect("legacy URLs are profile URLs");
+    assert_eq!(outcome.status, ProfileStatus::Exists);
+    assert_eq!(outcome.legacy_format, Some(LegacyFormat::PublicProfile));
+    assert_eq!(
+        outcome.canonical_url,
+        "https://www.linkedin.com/in/jane-doe-12345678"
+    );
+    assert_eq!(outcome.username, "jane-doe-12345678");
+
+    let result = validator.ai_validate(legacy_url);
+    assert_eq!(result.decision, AIDecision::Accept);
+    assert_eq!(
+        result.canonical_url.as_deref(),
+        Some("https://www.linkedin.com/in/jane-doe-12345678")
+    );
+    assert_eq!(result.username.as_deref(), Some("jane-doe-12345678"));
+}
+
+#[test]
+fn test_legacy_url_resolution_can_be_disabled() {
+    let validator = validator_with(vec![]).with_legacy_url_resolution(false);
+
+    assert!(matches!(
+        validator.validate("https://www.linkedin.com/profile/view?id=AAkAAA123"),
+        Err(LinkedInUrlError::LegacyProfileUrl(
+            LegacyFormat::ProfileView
+        ))
+    ));
+    let result = validator.ai_validate("https://www.linkedin.com/profile/view?id=AAkAAA123");
+    assert_eq!(
+        result.metadata.error_type.as_deref(),
+        Some("LEGACY_PROFILE_URL")
+    );
+    assert!(validator.transport().requests().is_empty());
+}
+
+#[test]
+fn test_short_link_is_resolved() {
+    let transport = ScriptedTransport::new();
+    transport.respond_to(
+        "https://lnkd.in/abc123",
+        HttpResponse::new(301, "https://lnkd.in/abc123")
+            .with_header("Location", "https://www.linkedin.com/in/johndoe?trk=short"),
+    );
+    transport.respond_to(PROFILE_URL, HttpResponse::new(200, PROFILE_URL));
+    let validator = LinkedInValidator::with_transport(transport).with_short_link_resolution(true);
+
+    let outcome = validator
+        .validate("https://lnkd.in/abc123")
+        .expect("short link leads to a profile");
+    assert_eq!(outcome.status, ProfileStatus::Exists);
+    assert_eq!(outcome.canonical_url, PROFILE_URL);
+    let short_link = outcome.short_link.expect("short link is recorded");
+    assert_eq!(short_link.url, "https://lnkd.in/abc123");
+    assert_eq!(short_link.hops, 1);
+
+    let result = validator.ai_validate("https://lnkd.in/abc123");
+    assert_eq!(result.decision, AIDecision::Accept);
+    assert_eq!(result.username.as_deref(), Some("johndoe"));
+    assert_eq!(
+        result.metadata.original_url.as_deref(),
+        Some("https://lnkd.in/abc123")
+    );
+    assert_eq!(
+        result.metadata.resolved_url.as_deref(),
+        Some("https://www.linkedin.com/in/johndoe?trk=short")
+    );
+
+    let requests = validator.transport().requests();
+    assert!(!requests[0].follow_redirects);
+    assert!(requests[1].follow_redirects);
+}
+
+#[test]
+fn test_short_link_failures() {
+    let transport = ScriptedTransport::new();
+    transport.respond_to(
+        "https://lnkd.in/loop1",
+        HttpResponse::new(302, "https://lnkd.in/loop1").with_header("Location", "/loop2"),
+    );
+    transport.respond_to(
+        "https://lnkd.in/loop2",
+        HttpResponse::new(302, "https://lnkd.in/loop2").with_header("Location", "/loop1"),
+    );
+    transport.respond_to(
+        "https://lnkd.in/company",
+        HttpResponse::new(301, "https://lnkd.in/company")
+            .with_header("Location", "https://www.linkedin.com/company/microsoft"),
+    );
+    let validator = LinkedInValidator::with_transport(transport).with_short_link_resolution(true);
+
+    assert!(matches!(
+        validator.validate("https://lnkd.in/loop1"),
+        Err(LinkedInUrlError::UnresolvedShortLink(
+            ShortLinkFailure::RedirectLoop(_)
+        ))
+    ));
+    assert!(matches!(
+        validator.validate("https://lnkd.in/company"),
+        Err(LinkedInUrlError::NotProfileUrl)
+    ));
+    let result = validator.ai_validate("https://lnkd.in/loop1");
+    assert_eq!(
+        result.metadata.error_type.as_deref(),
+        Some("SHORT_LINK_UNRESOLVED")
+    );
+
+    // Without opting in, short links are not LinkedIn URLs
+    let validator = validator_with(vec![]);
+    assert!(matches!(
+        validator.validate("https://lnkd.in/abc123"),
+        Err(LinkedInUrlError::NotLinkedInUrl)
+    ));
+}
+
+#[test]
+fn test_company_page_validation() {
+    let company_url = "https://www.linkedin.com/company/microsoft";
+    let transport = ScriptedTransport::new();
+    transport.respond_to(company_url, HttpResponse::new(200, company_url));
+    transport.respond_to(
+        "https://www.linkedin.com/company/no-such-company",
+        HttpResponse::new(200, "https://www.linkedin.com/404/"),
+    );
+    let validator = LinkedInValidator::with_transport(transport);
+
+    let outcome = validator
+        .validate_company_url("https://uk.linkedin.com/company/Microsoft/jobs/")
+        .expect("company pages are validated");
+    assert_eq!(outcome.entity, EntityKind::Company);
+    assert_eq!(outcome.status, ProfileStatus::Exists);
+    assert_eq!(outcome.canonical_url, company_url);
+    assert_eq!(outcome.username, "microsoft");
+    assert_eq!(outcome.host_variant, HostVariant::Country("uk".to_string()));
+
+    let outcome = validator
+        .validate_company_url("https://www.linkedin.com/company/no-such-company")
+        .expect("company pages are validated");
+    assert_eq!(outcome.status, ProfileStatus::NotFound);
+
+    assert!(matches!(
+        validator.validate_company_url(PROFILE_URL),
+        Err(LinkedInUrlError::NotCompanyUrl)
+    ));
+    assert_eq!(validator.transport().requests().len(), 2);
+}
+
+#[test]
+fn test_ai_validate_repairs_input() {
+    let validator = validator_with(vec![
+        HttpResponse::new(200, PROFILE_URL).with_body("<title>John Doe | LinkedIn</title>"),
+    ])
+    .with_url_repair(true);
+
+    let result = validator.ai_validate(" https//www.linkedin.com/in/johndoe ");
+    assert_eq!(result.decision, AIDecision::Accept);
+    assert!(result.confidence < 1.0);
+    assert!(result.reason.starts_with("Accepted after repair"));
+    let repair = result.metadata.repair.expect("repair is reported");
+    assert_eq!(repair.url, PROFILE_URL);
+    assert_eq!(
+        repair.repairs,
+        [UrlRepair::TrimmedWhitespace, UrlRepair::FixedScheme]
+    );
+    assert_eq!(validator.transport().requests()[0].url, PROFILE_URL);
+
+    // Without repair the same input is rejected before any request
+    let validator = validator_with(vec![]);
+    let result = validator.ai_validate("https//www.linkedin.com/in/johndoe");
+    assert_eq!(result.decision, AIDecision::Reject);
+    assert!(result.metadata.repair.is_none());
+    assert!(validator.transport().requests().is_empty());
+}
+
+#[test]
+fn test_throttled_request_backs_off_and_retries() {
+    let blocked = || HttpResponse::new(999, PROFILE_URL).with_header("Retry-After", "0");
+    let validator = validator_with(vec![
+        blocked(),
+        blocked(),
+        HttpResponse::new(200, PROFILE_URL).with_body("<title>John Doe | LinkedIn</title>"),
+    ])
+    .with_throttle(
+        ThrottlePolicy::new()
+            .max_retries(2)
+            .backoff(Duration::from_millis(1), Duration::from_millis(5)),
+    );
+
+    let outcome = validator
+        .validate(PROFILE_URL)
+        .expect("validation succeeds");
+    assert_eq!(outcome.status, ProfileStatus::Exists);
+    // 999, the cookie retry (999 again), then the backed-off retry
+    assert_eq!(validator.transport().requests().len(), 3);
+
+    let state = validator.throttle_state().expect("throttling is enabled");
+    assert_eq!((state.total_blocks, state.retries), (1, 1));
+    assert_eq!(state.consecutive_blocks, 0);
+    assert_eq!(state.last_retry_after, Some(Duration::ZERO));
+    assert!(validator_with(vec![]).throttle_state().is_none());
+}
+
+#[test]
+fn test_long_retry_after_is_reported_not_slept() {
+    let blocked = || HttpResponse::new(999, PROFILE_URL).with_header("Retry-After", "86400");
+    let validator = validator_with(vec![blocked(), blocked()])
+        .with_throttle(ThrottlePolicy::new().max_retries(3));
+
+    let outcome = validator
+        .validate(PROFILE_URL)
+        .expect("validation succeeds");
+    assert_eq!(
+        outcome.status,
+        ProfileStatus::RateLimited {
+            retry_after: Some(Duration::from_secs(86400))
+        }
+    );
+    // 999 and the cookie retry, without waiting a day for another attempt
+    assert_eq!(validator.transport().requests().len(), 2);
+}
+
+#[test]
+fn test_transport_error() {
+    let transport = ScriptedTransport::new();
+    transport.enqueue_error("connection refused");
+    let validator = LinkedInValidator::with_transport(transport);
+
+    assert!(matches!(
+        validator.is_valid_linkedin_profile_url(PROFILE_URL),
+        Err(LinkedInUrlError::TransportError(_))
+    ));
+}
+
+#[test]
+fn test_transient_error_is_retried() {
+    let transport = ScriptedTransport::new();
+    transport.enqueue_error("connection reset");
+    transport.enqueue(
+        HttpResponse::new(200, PROFILE_URL).with_body("<title>John Doe | LinkedIn</title>"),
+    );
+    let validator = LinkedInValidator::with_transport(transport)
+        .with_retry(RetryPolicy::new().backoff(RetryBackoff::Constant(Duration::ZERO)));
+
+    let result = validator.ai_validate(PROFILE_URL);
+    assert_eq!(result.decision, AIDecision::Accept);
+    assert_eq!(result.metadata.attempts, Some(2));
+
+    let transport = ScriptedTransport::new();
+    transport.enqueue_error("connection reset");
+    let result = LinkedInValidator::with_transport(transport).ai_validate(PROFILE_URL);
+    assert_ne!(result.decision, AIDecision::Accept);
+    assert_eq!(result.metadata.attempts, Some(1));
+}
+
+#[test]
+fn test_cookie_retry_and_company_pages_count_attempts() {
+    let validator = validator_with(vec![
+        HttpResponse::new(999, PROFILE_URL),
+        HttpResponse::new(200, PROFILE_URL).with_body("<title>John Doe | LinkedIn</title>"),
+    ]);
+    let result = validator.ai_validate(PROFILE_URL);
+    assert_eq!(result.decision, AIDecision::Accept);
+    assert_eq!(result.metadata.attempts, Some(2));
+
+    let company = "https://www.linkedin.com/company/microsoft";
+    let transport = ScriptedTransport::new();
+    transport.enqueue_error("connection reset");
+    transport.enqueue(HttpResponse::new(200, company));
+    let validator = LinkedInValidator::with_transport(transport)
+        .with_retry(RetryPolicy::new().backoff(RetryBackoff::Constant(Duration::ZERO)));
+    let outcome = validator
+        .validate_company_url(company)
+        .expect("validation succeeds");
+    assert_eq!(outcome.status, ProfileStatus::Exists);
+    assert_eq!(outcome.attempts, 2);
+}
+
+#[test]
+fn test_cached_result_skips_transport() {
+    let validator = validator_with(vec![
+        HttpResponse::new(200, PROFILE_URL).with_body("<title>John Doe | LinkedIn</title>"),
+    ])
+    .with_cache(CachePolicy::new());
+
+    let first = validator.ai_validate(PROFILE_URL);
+    assert_eq!(first.decision, AIDecision::Accept);
+    assert!(!first.metadata.cached);
+
+    let second = validator.ai_validate("https://uk.linkedin.com/in/JohnDoe/?trk=x");
+    assert_eq!(second.decision, AIDecision::Accept);
+    assert!(second.metadata.cached && second.metadata.cached_at.is_some());
+    assert_eq!(first.metadata.host_variant, Some(HostVariant::Www));
+    assert_eq!(
+        second.metadata.host_variant,
+        Some(HostVariant::Country("uk".to_string()))
+    );
+    assert_eq!(second.metadata.attempts, None);
+    assert_eq!(validator.transport().requests().len(), 1);
+
+    validator.clear_cache();
+    assert!(!validator.ai_validate(PROFILE_URL).metadata.cached);
+}
+
+#[test]
+fn test_custom_detection_rules() {
+    let page =
+        || HttpResponse::new(200, PROFILE_URL).with_body("<h1>This profile has moved on</h1>");
+    let rules = DetectionRules::from_json(
+        r#"{"rules": [{"name": "moved-on", "outcome": "not_found", "priority": 50,
+            "any": [{"body_contains": "profile has moved on"}]}]}"#,
+    )
+    .expect("rules are valid");
+
+    let validator = validator_with(vec![page()]);
+    let outcome = validator
+        .validate(PROFILE_URL)
+        .expect("validation succeeds");
+    assert_eq!(outcome.status, ProfileStatus::Exists);
+
+    let validator = validator_with(vec![page()]).with_detection_rules(rules);
+    let outcome = validator
+        .validate(PROFILE_URL)
+        .expect("validation succeeds");
+    assert_eq!(outcome.status, ProfileStatus::NotFound);
+}
+
+#[test]
+fn test_accept_language_and_localized_not_found_page() {
+    let validator = validator_with(vec![
+        HttpResponse::new(999, PROFILE_URL),
+        HttpResponse::new(200, PROFILE_URL)
+            .with_body("<h1>Diese Seite existiert nicht</h1><p>Seite nicht gefunden</p>"),
+    ])
+    .with_accept_language("de-DE");
+
+    let outcome = validator
+        .validate(PROFILE_URL)
+        .expect("validation succeeds");
+    assert_eq!(outcome.status, ProfileStatus::NotFound);
+
+    let requests = validator.transport().requests();
+    let language = ("Accept-Language".to_string(), "de-DE".to_string());
+    assert_eq!(requests[0].headers, vec![language.clone()]);
+    assert_eq!(
+        requests[1].headers,
+        vec![language, ("Cookie".to_string(), "sl=v=1&1".to_string())]
+    );
+}
+
+#[test]
+fn test_suggestions_within_budget_are_validated() {
+    let validator = validator_with(vec![
+        HttpResponse::new(404, "https://www.linkedin.com/in/john-doe"),
+        HttpResponse::new(200, PROFILE_URL).with_body("<title>John Doe | LinkedIn</title>"),
+    ]);
+    let options = SuggestOptions::new().validation_budget(2);
+    let suggestions = validator.suggest_profile_urls("John", "Doe", &options);
+
+    let found: Vec<(&str, Option<bool>)> = suggestions
+        .iter()
+        .take(3)
+        .map(|suggestion| (suggestion.username.as_str(), suggestion.exists()))
+        .collect();
+    assert_eq!(
+        found,
+        [
+            ("john-doe", Some(false)),
+            ("johndoe", Some(true)),
+            ("jdoe", None)
+        ]
+    );
+    assert_eq!(validator.transport().requests().len(), 2);
+}
+
+#[test]
+fn test_profile_summary_is_opt_in() {
+    let page = || {
+        HttpResponse::new(200, PROFILE_URL).with_body(
+            "<title>John Doe - Engineer at Acme | LinkedIn</title>\
+             <meta property=\"og:image\" content=\"https://media.licdn.com/john.jpg\">",
+        )
+    };
+
+    let result = validator_with(vec![page()])
+        .with_profile_summary(true)
+        .ai_validate(PROFILE_URL);
+    let summary = result.profile_summary.expect("the page was served");
+    assert_eq!(summary.display_name.as_deref(), Some("John Doe"));
+    assert_eq!(summary.headline.as_deref(), Some("Engineer at Acme"));
+    assert_eq!(
+        summary.photo_url.as_deref(),
+        Some("https://media.licdn.com/john.jpg")
+    );
+
+    let result = validator_with(vec![page()]).ai_validate(PROFILE_URL);
+    assert!(result.profile_summary.is_none());
+}
+
+#[test]
+fn test_format_errors_skip_transport() {
+    let validator = validator_with(vec![]);
+
+    assert!(matches!(
+        validator.is_valid_linkedin_profile_url("https://linkedin.com/company/microsoft"),
+        Err(LinkedInUrlError::NotProfileUrl)
+    ));
+    assert!(validator.transport().requests().is_empty());
+}
+
+#[tokio::test]
+async fn test_async_match_profile() {
+    let transport = ScriptedTransport::new();
+    transport.respond_to(
+        PROFILE_URL,
+        HttpResponse::new(200, PROFILE_URL)
+            .with_body("<title>John Doe - Engineer at Acme | LinkedIn</title>"),
+    );
+    transport.respond_to(
+        "https://www.linkedin.com/in/nobody",
+        HttpResponse::new(200, "https://www.linkedin.com/in/nobody").with_body("Page not found"),
+    );
+    let validator = AsyncLinkedInValidator::with_transport(transport).with_profile_summary(true);
+
+    let identity = validator
+        .match_profile(&PersonHint::new("John Doe").company("Acme"), PROFILE_URL)
+        .await;
+    assert_eq!(identity.verdict, MatchVerdict::Likely);
+    assert_eq!(identity.signals[0].kind, MatchSignalKind::DisplayName);
+
+    let identity = validator
+        .match_profile(&PersonHint::new("Jane Smith"), PROFILE_URL)
+        .await;
+    assert_eq!(identity.verdict, MatchVerdict::Unlikely);
+
+    let identity = validator
+        .match_profile(
+            &PersonHint::new("John Doe"),
+            "https://www.linkedin.com/in/nobody",
+        )
+        .await;
+    assert_eq!(identity.score, 0.0);
+    assert_eq!(identity.signals[0].kind, MatchSignalKind::Existence);
+}
+
+#[tokio::test]
+async fn test_async_routes() {
+    let transport = ScriptedTransport::new();
+    transport.respond_to(
+        PROFILE_URL,
+        HttpResponse::new(200, PROFILE_URL).with_body("<title>John Doe | LinkedIn</title>"),
+    );
+    transport.respond_to(
+        "https://www.linkedin.com/in/nobody",
+        HttpResponse::new(200, "https://www.linkedin.com/in/nobody").with_body("Page not found"),
+    );
+
+    assert!(matches!(
+        validate_linkedin_url_with_transport(&transport, PROFILE_URL).await,
+        Ok(true)
+    ));
+    assert!(matches!(
+        validate_linkedin_url_with_transport(&transport, "https://www.linkedin.com/in/nobody")
+            .await,
+        Err(LinkedInUrlError::ProfileNotFound)
+    ));
+}
+
+#[tokio::test]
+async fn test_async_validate_outcome() {
+    let transport = ScriptedTransport::new();
+    transport.enqueue(
+        HttpResponse::new(200, PROFILE_URL)
+            .with_body(r#"<script>window.location = "/authwall?trk=foo";</script>"#),
+    );
+    let validator = AsyncLinkedInValidator::with_transport(transport);
+
+    let outcome = validator
+        .validate(PROFILE_URL)
+        .await
+        .expect("validation should succeed");
+    assert!(matches!(outcome.status, ProfileStatus::LikelyExists { .. }));
+    assert!(outcome.status.is_likely_valid());
+    assert!(!outcome.status.is_conclusive());
+}
+
+#[tokio::test]
+async fn test_async_company_page_validation() {
+    let transport = ScriptedTransport::new();
+    transport.enqueue(HttpResponse::new(
+        999,
+        "https://www.linkedin.com/company/microsoft",
+    ));
+    transport.enqueue(
+        HttpResponse::new(200, "https://www.linkedin.com/company/microsoft")
+            .with_body(r#"<a href="https://www.linkedin.com/authwall?trk=foo">Sign in</a>"#),
+    );
+    let validator = AsyncLinkedInValidator::with_transport(transport);
+
+    let outcome = validator
+        .validate_company_url("https://www.linkedin.com/company/microsoft")
+        .await
+        .expect("company pages are validated");
+    assert_eq!(outcome.entity, EntityKind::Company);
+    assert!(matches!(outcome.status, ProfileStatus::LikelyExists { .. }));
+    assert_eq!(validator.transport().requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_async_short_link_is_resolved() {
+    let transport = ScriptedTransport::new();
+    transport.respond_to(
+        "https://lnkd.in/abc123",
+        HttpResponse::new(301, "https://lnkd.in/abc123").with_header("Location", PROFILE_URL),
+    );
+    transport.respond_to(PROFILE_URL, HttpResponse::new(200, PROFILE_URL));
+    let validator =
+        AsyncLinkedInValidator::with_transport(transport).with_short_link_resolution(true);
+
+    let outcome = validator
+        .validate("https://lnkd.in/abc123")
+        .await
+        .expect("short link leads to a profile");
+    assert_eq!(outcome.status, ProfileStatus::Exists);
+    assert_eq!(
+        outcome.short_link.map(|link| link.resolved_url),
+        Some(PROFILE_URL.to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_async_validator_shares_file_cache() {
+    let dir = std::env::temp_dir().join(format!("credify-async-cache-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    let store = FileCache::open(&dir).expect("cache opens");
+    let transport = ScriptedTransport::new();
+    transport.respond_to(
+        PROFILE_URL,
+        HttpResponse::new(200, PROFILE_URL).with_body("<title>John Doe | LinkedIn</title>"),
+    );
+    let validator = AsyncLinkedInValidator::with_transport(transport)
+        .with_cache_store(CachePolicy::new(), store);
+
+    assert!(!validator.ai_validate(PROFILE_URL).await.metadata.cached);
+    let again = validator.ai_validate(PROFILE_URL).await;
+    assert!(again.metadata.cached);
+    assert_eq!(validator.transport().requests().len(), 1);
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[tokio::test]
+async fn test_async_batch_validates_each_profile_once() {
+    let nobody = "https://www.linkedin.com/in/nobody";
+    let transport = ScriptedTransport::new();
+    transport.respond_to(
+        PROFILE_URL,
+        HttpResponse::new(200, PROFILE_URL).with_body("<title>John Doe | LinkedIn</title>"),
+    );
+    transport.respond_to(
+        nobody,
+        HttpResponse::new(200, nobody).with_body("Page not found"),
+    );
+    let validator = AsyncLinkedInValidator::with_transport(transport);
+
+    let inputs = [
+        PROFILE_URL,
+        "https://uk.linkedin.com/in/JohnDoe/?trk=search",
+        nobody,
+        "not a url",
+    ];
+    let options = BatchOptions::new().concurrency(2).unlimited();
+    let mut results = validator.validate_batch(inputs, options).await;
+    results.sort_by_key(|(input, _)| inputs.iter().position(|url| url == input));
+
+    let decisions: Vec<(&str, AIDecision)> = results
+        .iter()
+        .map(|(input, result)| (input.as_str(), result.decision))
+        .collect();
+    assert_eq!(
+        decisions,
+        [
+            (PROFILE_URL, AIDecision::Accept),
+            (
+                "https://uk.linkedin.com/in/JohnDoe/?trk=search",
+                AIDecision::Accept
+            ),
+            (nobody, AIDecision::Reject),
+            ("not a url", AIDecision::Reject),
+        ]
+    );
+    // The two spellings of the same profile share one request
+    assert_eq!(validator.transport().requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_async_batch_remembers_finished_validations() {
+    let transport = ScriptedTransport::new();
+    transport.respond_to(
+        PROFILE_URL,
+        HttpResponse::new(200, PROFILE_URL).with_body("<title>John Doe | LinkedIn</title>"),
+    );
+    let validator = AsyncLinkedInValidator::with_transport(transport);
+
+    let inputs = [
+        PROFILE_URL,
+        "https://uk.linkedin.com/in/JohnDoe/",
+        "not a url",
+        "https://www.linkedin.com/in/johndoe?trk=search",
+    ];
+    let options = BatchOptions::new()
+        .concurrency(2)
+        .unlimited()
+        .remember_results(0);
+    let results = validator.validate_batch(inputs, options).await;
+
+    assert_eq!(results.len(), 4);
+    assert!(
+        results
+            .iter()
+            .filter(|(input, _)| input != "not a url")
+            .all(|(_, result)| result.decision == AIDecision::Accept)
+    );
+    // The last input arrives after the first validation finished and reuses its result,
+    // even though a stream with these options would forget it
+    assert_eq!(validator.transport().requests().len(), 1);
+}
+
+#[tokio::test]
+async fn test_async_stream_forgets_results_beyond_its_memory() {
+    let transport = ScriptedTransport::new();
+    transport.respond_to(
+        PROFILE_URL,
+        HttpResponse::new(200, PROFILE_URL).with_body("<title>John Doe | LinkedIn</title>"),
+    );
+    let validator = AsyncLinkedInValidator::with_transport(transport);
+
+    let inputs = futures_util::stream::iter([
+        PROFILE_URL,
+        "https://uk.linkedin.com/in/JohnDoe/",
+        "not a url",
+        "https://www.linkedin.com/in/johndoe?trk=search",
+    ]);
+    let options = BatchOptions::new()
+        .concurrency(2)
+        .unlimited()
+        .remember_results(0);
+    let results: Vec<_> = validator.validate_stream(inputs, options).collect().await;
+
+    assert_eq!(results.len(), 4);
+    // The first two inputs overlap and share a request; with no memory the last one is
+    // validated again
+    assert_eq!(validator.transport().requests().len(), 2);
+}
+
+#[tokio::test]
+async fn test_async_circuit_breaker_is_shared_by_clones() {
+    let transport = ScriptedTransport::new();
+    transport.respond_to(PROFILE_URL, HttpResponse::new(429, PROFILE_URL));
+    let validator = AsyncLinkedInValidator::with_transport(transport).with_throttle(
+        ThrottlePolicy::new()
+            .max_retries(0)
+            .circuit_breaker(2, Duration::from_secs(600)),
+    );
+    let clone = validator.clone();
+
+    let outcome = validator.validate(PROFILE_URL).await.expect("request sent");
+    assert!(matches!(outcome.status, ProfileStatus::RateLimited { .. }));
+    assert!(!clone.throttle_state().expect("enabled").is_circuit_open());
+
+    clone.validate(PROFILE_URL).await.expect("request sent");
+    let state = validator.throttle_state().expect("enabled");
+    assert!(state.is_circuit_open());
+    assert_eq!(state.consecutive_blocks, 2);
+}